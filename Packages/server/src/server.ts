@@ -1,5 +1,4 @@
 import { generateTypes } from "@hedystia/types";
-import type { StandardSchemaV1 } from "@standard-schema/spec";
 import Core from "./core";
 import generateCorsHeaders from "./handlers/cors";
 import processGenericHandlers from "./handlers/generic";
@@ -212,7 +211,7 @@ export class Hedystia<
         }
 
         if (paramsSchema) {
-          const result = paramsSchema.validate(params);
+          const result = await paramsSchema.validate(params);
           if (result.issues) {
             throw { statusCode: 400, message: "Invalid params" };
           }
@@ -222,7 +221,7 @@ export class Hedystia<
         }
 
         if (querySchema) {
-          const result = querySchema.validate(query);
+          const result = await querySchema.validate(query);
           if (result.issues) {
             throw { statusCode: 400, message: "Invalid query parameters" };
           }
@@ -238,13 +237,13 @@ export class Hedystia<
 
         let headers: Record<string, string> = { ...rawHeaders };
         if (this.defaultHeaders) {
-          const globalResult = this.defaultHeaders["~standard"].validate(rawHeaders);
+          const globalResult = await this.defaultHeaders["~standard"].validate(rawHeaders);
           if (globalResult && "value" in globalResult) {
             headers = { ...headers, ...globalResult.value };
           }
         }
         if (headersSchema) {
-          const result = headersSchema.validate(rawHeaders);
+          const result = await headersSchema.validate(rawHeaders);
           if (result.issues) {
             throw { statusCode: 400, message: "Invalid header value" };
           }
@@ -284,8 +283,8 @@ export class Hedystia<
             }
 
             if (bodySchema?.["~standard"]) {
-              const result = bodySchema["~standard"].validate(body);
-              if (result instanceof Promise ? (await result).issues : result.issues) {
+              const result = await bodySchema["~standard"].validate(body);
+              if (result.issues) {
                 throw { statusCode: 400, message: "Invalid body" };
               }
               if ("value" in result) {
@@ -566,7 +565,7 @@ export class Hedystia<
 
         let validatedParams = params || {};
         if (handlerData.schema.params) {
-          const result = handlerData.schema.params["~standard"].validate(params) as any;
+          const result = (await handlerData.schema.params["~standard"].validate(params)) as any;
           if ("issues" in result) {
             return new Response(JSON.stringify({ error: "Invalid params" }), {
               status: 400,
@@ -578,7 +577,7 @@ export class Hedystia<
 
         let validatedQuery = query || {};
         if (handlerData.schema.query) {
-          const result = handlerData.schema.query["~standard"].validate(query) as any;
+          const result = (await handlerData.schema.query["~standard"].validate(query)) as any;
           if ("issues" in result) {
             return new Response(JSON.stringify({ error: "Invalid query" }), {
               status: 400,
@@ -590,13 +589,15 @@ export class Hedystia<
 
         let validatedHeaders: Record<string, string> = { ...rawHeaders };
         if (this.defaultHeaders) {
-          const globalResult = this.defaultHeaders["~standard"].validate(rawHeaders) as any;
+          const globalResult = (await this.defaultHeaders["~standard"].validate(rawHeaders)) as any;
           if (globalResult && "value" in globalResult) {
             validatedHeaders = { ...validatedHeaders, ...globalResult.value };
           }
         }
         if (handlerData.schema.headers) {
-          const result = handlerData.schema.headers["~standard"].validate(rawHeaders) as any;
+          const result = (await handlerData.schema.headers["~standard"].validate(
+            rawHeaders,
+          )) as any;
           if ("issues" in result) {
             return new Response(JSON.stringify({ error: "Invalid headers" }), {
               status: 400,
@@ -1028,9 +1029,7 @@ export class Hedystia<
         if (type === "subscribe") {
           let validatedParams = rawParams || {};
           if (matchedSub.schema.params) {
-            const result = matchedSub.schema.params["~standard"].validate(
-              rawParams,
-            ) as StandardSchemaV1.Result<any>;
+            const result = await matchedSub.schema.params["~standard"].validate(rawParams);
             if ("issues" in result) {
               return;
             }
@@ -1039,9 +1038,7 @@ export class Hedystia<
 
           let validatedQuery = query || {};
           if (matchedSub.schema.query && query) {
-            const result = matchedSub.schema.query["~standard"].validate(
-              query,
-            ) as StandardSchemaV1.Result<any>;
+            const result = await matchedSub.schema.query["~standard"].validate(query);
             if ("issues" in result) {
               console.error("Validation error (query):", result.issues);
               return;
@@ -1051,17 +1048,13 @@ export class Hedystia<
 
           let validatedHeaders = headers || {};
           if (this.defaultHeaders) {
-            const globalResult = this.defaultHeaders["~standard"].validate(
-              headers || {},
-            ) as StandardSchemaV1.Result<any>;
+            const globalResult = await this.defaultHeaders["~standard"].validate(headers || {});
             if (globalResult && "value" in globalResult) {
               validatedHeaders = { ...validatedHeaders, ...globalResult.value };
             }
           }
           if (matchedSub.schema.headers && headers) {
-            const result = matchedSub.schema.headers["~standard"].validate(
-              headers,
-            ) as StandardSchemaV1.Result<any>;
+            const result = await matchedSub.schema.headers["~standard"].validate(headers);
             if ("issues" in result) {
               console.error("Validation error (headers):", result.issues);
               ws.send(
@@ -1228,10 +1221,7 @@ export class Hedystia<
           let validatedData = data;
           const handlerInfo = this.messageHandlers.get(subscriptionId);
           if (handlerInfo) {
-            let validationResult = handlerInfo.schema["~standard"].validate(data);
-            if (validationResult instanceof Promise) {
-              validationResult = await validationResult;
-            }
+            const validationResult = await handlerInfo.schema["~standard"].validate(data);
 
             if ("issues" in validationResult) {
               console.error("[WS] Message validation error:", validationResult.issues);
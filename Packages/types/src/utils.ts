@@ -1,15 +1,31 @@
 import {
   AnySchemaType,
   ArraySchema,
+  BigIntSchemaType,
   BooleanSchemaType,
+  BrandedSchema,
+  CatchSchema,
+  DateSchemaType,
+  DefaultSchema,
+  DiscriminatedUnionSchema,
+  FunctionSchemaType,
   InstanceOfSchema,
+  IntersectionSchema,
   LiteralSchema,
+  MapSchema,
+  NeverSchemaType,
+  NullableSchema,
   NullSchemaType,
   NumberSchemaType,
   ObjectSchemaType,
   OptionalSchema,
+  PipeSchema,
+  PromiseSchema,
+  SetSchema,
   StringSchemaType,
+  TupleSchema,
   UnionSchema,
+  VoidSchemaType,
 } from "@hedystia/validations";
 
 export function schemaToTypeString(schema: any): string {
@@ -23,21 +39,58 @@ export function schemaToTypeString(schema: any): string {
   if (schema instanceof NumberSchemaType) {
     return "number";
   }
+  if (schema instanceof BigIntSchemaType) {
+    return "bigint";
+  }
   if (schema instanceof BooleanSchemaType) {
     return "boolean";
   }
+  if (schema instanceof DateSchemaType) {
+    return "Date";
+  }
   if (schema instanceof NullSchemaType) {
     return "null";
   }
   if (schema instanceof AnySchemaType) {
     return "any";
   }
+  if (schema instanceof NeverSchemaType) {
+    return "never";
+  }
+  if (schema instanceof VoidSchemaType) {
+    return "void";
+  }
+  if (schema instanceof FunctionSchemaType) {
+    return "(...args: any[]) => unknown";
+  }
 
   if (schema instanceof OptionalSchema) {
     const inner = (schema as any).innerSchema;
     return `${schemaToTypeString(inner)} | undefined`;
   }
 
+  if (schema instanceof CatchSchema) {
+    return schemaToTypeString((schema as any).innerSchema);
+  }
+
+  if (schema instanceof PromiseSchema) {
+    return `Promise<${schemaToTypeString((schema as any).innerSchema)}>`;
+  }
+
+  if (schema instanceof PipeSchema) {
+    return schemaToTypeString((schema as any).nextSchema);
+  }
+
+  if (schema instanceof DefaultSchema) {
+    const inner = (schema as any).innerSchema;
+    return schemaToTypeString(inner).replace(" | undefined", "");
+  }
+
+  if (schema instanceof NullableSchema) {
+    const inner = (schema as any).innerSchema;
+    return `${schemaToTypeString(inner)} | null`;
+  }
+
   if (schema instanceof ArraySchema) {
     const inner = (schema as any).innerSchema;
     const innerType = schemaToTypeString(inner);
@@ -46,7 +99,31 @@ export function schemaToTypeString(schema: any): string {
       : `${innerType}[]`;
   }
 
-  if (schema instanceof UnionSchema) {
+  if (schema instanceof BrandedSchema) {
+    const inner = schemaToTypeString((schema as any).innerSchema);
+    return `(${inner}) & { readonly __brand: '${schema.brandName}' }`;
+  }
+
+  if (schema instanceof MapSchema) {
+    const key = schemaToTypeString((schema as any).keySchema);
+    const value = schemaToTypeString((schema as any).valueSchema);
+    return `Map<${key}, ${value}>`;
+  }
+
+  if (schema instanceof SetSchema) {
+    return `Set<${schemaToTypeString((schema as any).innerSchema)}>`;
+  }
+
+  if (schema instanceof TupleSchema) {
+    const items = ((schema as any).items || []).map((s: any) => schemaToTypeString(s));
+    const rest = (schema as any).restSchema;
+    if (rest) {
+      items.push(`...${schemaToTypeString(rest)}[]`);
+    }
+    return `[${items.join(",")}]`;
+  }
+
+  if (schema instanceof UnionSchema || schema instanceof DiscriminatedUnionSchema) {
     const schemas = (schema as any).schemas || [];
     if (schemas.length === 0) {
       return "any";
@@ -54,6 +131,12 @@ export function schemaToTypeString(schema: any): string {
     return schemas.map((s: any) => schemaToTypeString(s)).join(" | ");
   }
 
+  if (schema instanceof IntersectionSchema) {
+    const left = schemaToTypeString((schema as any).left);
+    const right = schemaToTypeString((schema as any).right);
+    return `(${left}) & (${right})`;
+  }
+
   if (schema instanceof LiteralSchema) {
     const val = (schema as any).value;
     return typeof val === "string" ? `'${val}'` : String(val);
@@ -66,8 +149,10 @@ export function schemaToTypeString(schema: any): string {
 
   if (schema instanceof ObjectSchemaType) {
     const definition = (schema as any).definition;
+    const catchall = (schema as any)._catchall;
+    const extra = catchall ? `Record<string, ${schemaToTypeString(catchall)}>` : "";
     if (!definition || Object.keys(definition).length === 0) {
-      return "{}";
+      return extra || "{}";
     }
 
     const validIdentifierRegex = /^[a-zA-Z_$][a-zA-Z0-9_$]*$/;
@@ -87,7 +172,7 @@ export function schemaToTypeString(schema: any): string {
       })
       .join(";");
 
-    return `{${properties}}`;
+    return extra ? `{${properties}} & ${extra}` : `{${properties}}`;
   }
 
   return "any";
@@ -0,0 +1,727 @@
+/** ISO 4217 active currency codes */
+export const CURRENCY_CODES = [
+  "AED",
+  "AFN",
+  "ALL",
+  "AMD",
+  "AOA",
+  "ARS",
+  "AUD",
+  "AWG",
+  "AZN",
+  "BAM",
+  "BBD",
+  "BDT",
+  "BGN",
+  "BHD",
+  "BIF",
+  "BMD",
+  "BND",
+  "BOB",
+  "BOV",
+  "BRL",
+  "BSD",
+  "BTN",
+  "BWP",
+  "BYN",
+  "BZD",
+  "CAD",
+  "CDF",
+  "CHE",
+  "CHF",
+  "CHW",
+  "CLF",
+  "CLP",
+  "CNY",
+  "COP",
+  "COU",
+  "CRC",
+  "CUP",
+  "CVE",
+  "CZK",
+  "DJF",
+  "DKK",
+  "DOP",
+  "DZD",
+  "EGP",
+  "ERN",
+  "ETB",
+  "EUR",
+  "FJD",
+  "FKP",
+  "GBP",
+  "GEL",
+  "GHS",
+  "GIP",
+  "GMD",
+  "GNF",
+  "GTQ",
+  "GYD",
+  "HKD",
+  "HNL",
+  "HTG",
+  "HUF",
+  "IDR",
+  "ILS",
+  "INR",
+  "IQD",
+  "IRR",
+  "ISK",
+  "JMD",
+  "JOD",
+  "JPY",
+  "KES",
+  "KGS",
+  "KHR",
+  "KMF",
+  "KPW",
+  "KRW",
+  "KWD",
+  "KYD",
+  "KZT",
+  "LAK",
+  "LBP",
+  "LKR",
+  "LRD",
+  "LSL",
+  "LYD",
+  "MAD",
+  "MDL",
+  "MGA",
+  "MKD",
+  "MMK",
+  "MNT",
+  "MOP",
+  "MRU",
+  "MUR",
+  "MVR",
+  "MWK",
+  "MXN",
+  "MXV",
+  "MYR",
+  "MZN",
+  "NAD",
+  "NGN",
+  "NIO",
+  "NOK",
+  "NPR",
+  "NZD",
+  "OMR",
+  "PAB",
+  "PEN",
+  "PGK",
+  "PHP",
+  "PKR",
+  "PLN",
+  "PYG",
+  "QAR",
+  "RON",
+  "RSD",
+  "RUB",
+  "RWF",
+  "SAR",
+  "SBD",
+  "SCR",
+  "SDG",
+  "SEK",
+  "SGD",
+  "SHP",
+  "SLE",
+  "SOS",
+  "SRD",
+  "SSP",
+  "STN",
+  "SVC",
+  "SYP",
+  "SZL",
+  "THB",
+  "TJS",
+  "TMT",
+  "TND",
+  "TOP",
+  "TRY",
+  "TTD",
+  "TWD",
+  "TZS",
+  "UAH",
+  "UGX",
+  "USD",
+  "USN",
+  "UYI",
+  "UYU",
+  "UYW",
+  "UZS",
+  "VED",
+  "VES",
+  "VND",
+  "VUV",
+  "WST",
+  "XAF",
+  "XAG",
+  "XAU",
+  "XBA",
+  "XBB",
+  "XBC",
+  "XBD",
+  "XCD",
+  "XCG",
+  "XDR",
+  "XOF",
+  "XPD",
+  "XPF",
+  "XPT",
+  "XSU",
+  "XTS",
+  "XUA",
+  "XXX",
+  "YER",
+  "ZAR",
+  "ZMW",
+  "ZWG",
+] as const;
+
+/** ISO 3166-1 alpha-2 country codes */
+export const COUNTRY_CODES_ALPHA2 = [
+  "AD",
+  "AE",
+  "AF",
+  "AG",
+  "AI",
+  "AL",
+  "AM",
+  "AO",
+  "AQ",
+  "AR",
+  "AS",
+  "AT",
+  "AU",
+  "AW",
+  "AX",
+  "AZ",
+  "BA",
+  "BB",
+  "BD",
+  "BE",
+  "BF",
+  "BG",
+  "BH",
+  "BI",
+  "BJ",
+  "BL",
+  "BM",
+  "BN",
+  "BO",
+  "BQ",
+  "BR",
+  "BS",
+  "BT",
+  "BV",
+  "BW",
+  "BY",
+  "BZ",
+  "CA",
+  "CC",
+  "CD",
+  "CF",
+  "CG",
+  "CH",
+  "CI",
+  "CK",
+  "CL",
+  "CM",
+  "CN",
+  "CO",
+  "CR",
+  "CU",
+  "CV",
+  "CW",
+  "CX",
+  "CY",
+  "CZ",
+  "DE",
+  "DJ",
+  "DK",
+  "DM",
+  "DO",
+  "DZ",
+  "EC",
+  "EE",
+  "EG",
+  "EH",
+  "ER",
+  "ES",
+  "ET",
+  "FI",
+  "FJ",
+  "FK",
+  "FM",
+  "FO",
+  "FR",
+  "GA",
+  "GB",
+  "GD",
+  "GE",
+  "GF",
+  "GG",
+  "GH",
+  "GI",
+  "GL",
+  "GM",
+  "GN",
+  "GP",
+  "GQ",
+  "GR",
+  "GS",
+  "GT",
+  "GU",
+  "GW",
+  "GY",
+  "HK",
+  "HM",
+  "HN",
+  "HR",
+  "HT",
+  "HU",
+  "ID",
+  "IE",
+  "IL",
+  "IM",
+  "IN",
+  "IO",
+  "IQ",
+  "IR",
+  "IS",
+  "IT",
+  "JE",
+  "JM",
+  "JO",
+  "JP",
+  "KE",
+  "KG",
+  "KH",
+  "KI",
+  "KM",
+  "KN",
+  "KP",
+  "KR",
+  "KW",
+  "KY",
+  "KZ",
+  "LA",
+  "LB",
+  "LC",
+  "LI",
+  "LK",
+  "LR",
+  "LS",
+  "LT",
+  "LU",
+  "LV",
+  "LY",
+  "MA",
+  "MC",
+  "MD",
+  "ME",
+  "MF",
+  "MG",
+  "MH",
+  "MK",
+  "ML",
+  "MM",
+  "MN",
+  "MO",
+  "MP",
+  "MQ",
+  "MR",
+  "MS",
+  "MT",
+  "MU",
+  "MV",
+  "MW",
+  "MX",
+  "MY",
+  "MZ",
+  "NA",
+  "NC",
+  "NE",
+  "NF",
+  "NG",
+  "NI",
+  "NL",
+  "NO",
+  "NP",
+  "NR",
+  "NU",
+  "NZ",
+  "OM",
+  "PA",
+  "PE",
+  "PF",
+  "PG",
+  "PH",
+  "PK",
+  "PL",
+  "PM",
+  "PN",
+  "PR",
+  "PS",
+  "PT",
+  "PW",
+  "PY",
+  "QA",
+  "RE",
+  "RO",
+  "RS",
+  "RU",
+  "RW",
+  "SA",
+  "SB",
+  "SC",
+  "SD",
+  "SE",
+  "SG",
+  "SH",
+  "SI",
+  "SJ",
+  "SK",
+  "SL",
+  "SM",
+  "SN",
+  "SO",
+  "SR",
+  "SS",
+  "ST",
+  "SV",
+  "SX",
+  "SY",
+  "SZ",
+  "TC",
+  "TD",
+  "TF",
+  "TG",
+  "TH",
+  "TJ",
+  "TK",
+  "TL",
+  "TM",
+  "TN",
+  "TO",
+  "TR",
+  "TT",
+  "TV",
+  "TW",
+  "TZ",
+  "UA",
+  "UG",
+  "UM",
+  "US",
+  "UY",
+  "UZ",
+  "VA",
+  "VC",
+  "VE",
+  "VG",
+  "VI",
+  "VN",
+  "VU",
+  "WF",
+  "WS",
+  "YE",
+  "YT",
+  "ZA",
+  "ZM",
+  "ZW",
+] as const;
+
+/** ISO 3166-1 alpha-3 country codes */
+export const COUNTRY_CODES_ALPHA3 = [
+  "AND",
+  "ARE",
+  "AFG",
+  "ATG",
+  "AIA",
+  "ALB",
+  "ARM",
+  "AGO",
+  "ATA",
+  "ARG",
+  "ASM",
+  "AUT",
+  "AUS",
+  "ABW",
+  "ALA",
+  "AZE",
+  "BIH",
+  "BRB",
+  "BGD",
+  "BEL",
+  "BFA",
+  "BGR",
+  "BHR",
+  "BDI",
+  "BEN",
+  "BLM",
+  "BMU",
+  "BRN",
+  "BOL",
+  "BES",
+  "BRA",
+  "BHS",
+  "BTN",
+  "BVT",
+  "BWA",
+  "BLR",
+  "BLZ",
+  "CAN",
+  "CCK",
+  "COD",
+  "CAF",
+  "COG",
+  "CHE",
+  "CIV",
+  "COK",
+  "CHL",
+  "CMR",
+  "CHN",
+  "COL",
+  "CRI",
+  "CUB",
+  "CPV",
+  "CUW",
+  "CXR",
+  "CYP",
+  "CZE",
+  "DEU",
+  "DJI",
+  "DNK",
+  "DMA",
+  "DOM",
+  "DZA",
+  "ECU",
+  "EST",
+  "EGY",
+  "ESH",
+  "ERI",
+  "ESP",
+  "ETH",
+  "FIN",
+  "FJI",
+  "FLK",
+  "FSM",
+  "FRO",
+  "FRA",
+  "GAB",
+  "GBR",
+  "GRD",
+  "GEO",
+  "GUF",
+  "GGY",
+  "GHA",
+  "GIB",
+  "GRL",
+  "GMB",
+  "GIN",
+  "GLP",
+  "GNQ",
+  "GRC",
+  "SGS",
+  "GTM",
+  "GUM",
+  "GNB",
+  "GUY",
+  "HKG",
+  "HMD",
+  "HND",
+  "HRV",
+  "HTI",
+  "HUN",
+  "IDN",
+  "IRL",
+  "ISR",
+  "IMN",
+  "IND",
+  "IOT",
+  "IRQ",
+  "IRN",
+  "ISL",
+  "ITA",
+  "JEY",
+  "JAM",
+  "JOR",
+  "JPN",
+  "KEN",
+  "KGZ",
+  "KHM",
+  "KIR",
+  "COM",
+  "KNA",
+  "PRK",
+  "KOR",
+  "KWT",
+  "CYM",
+  "KAZ",
+  "LAO",
+  "LBN",
+  "LCA",
+  "LIE",
+  "LKA",
+  "LBR",
+  "LSO",
+  "LTU",
+  "LUX",
+  "LVA",
+  "LBY",
+  "MAR",
+  "MCO",
+  "MDA",
+  "MNE",
+  "MAF",
+  "MDG",
+  "MHL",
+  "MKD",
+  "MLI",
+  "MMR",
+  "MNG",
+  "MAC",
+  "MNP",
+  "MTQ",
+  "MRT",
+  "MSR",
+  "MLT",
+  "MUS",
+  "MDV",
+  "MWI",
+  "MEX",
+  "MYS",
+  "MOZ",
+  "NAM",
+  "NCL",
+  "NER",
+  "NFK",
+  "NGA",
+  "NIC",
+  "NLD",
+  "NOR",
+  "NPL",
+  "NRU",
+  "NIU",
+  "NZL",
+  "OMN",
+  "PAN",
+  "PER",
+  "PYF",
+  "PNG",
+  "PHL",
+  "PAK",
+  "POL",
+  "SPM",
+  "PCN",
+  "PRI",
+  "PSE",
+  "PRT",
+  "PLW",
+  "PRY",
+  "QAT",
+  "REU",
+  "ROU",
+  "SRB",
+  "RUS",
+  "RWA",
+  "SAU",
+  "SLB",
+  "SYC",
+  "SDN",
+  "SWE",
+  "SGP",
+  "SHN",
+  "SVN",
+  "SJM",
+  "SVK",
+  "SLE",
+  "SMR",
+  "SEN",
+  "SOM",
+  "SUR",
+  "SSD",
+  "STP",
+  "SLV",
+  "SXM",
+  "SYR",
+  "SWZ",
+  "TCA",
+  "TCD",
+  "ATF",
+  "TGO",
+  "THA",
+  "TJK",
+  "TKL",
+  "TLS",
+  "TKM",
+  "TUN",
+  "TON",
+  "TUR",
+  "TTO",
+  "TUV",
+  "TWN",
+  "TZA",
+  "UKR",
+  "UGA",
+  "UMI",
+  "USA",
+  "URY",
+  "UZB",
+  "VAT",
+  "VCT",
+  "VEN",
+  "VGB",
+  "VIR",
+  "VNM",
+  "VUT",
+  "WLF",
+  "WSM",
+  "YEM",
+  "MYT",
+  "ZAF",
+  "ZMB",
+  "ZWE",
+] as const;
+
+/** Postal code patterns by ISO 3166-1 alpha-2 country code */
+export const POSTAL_CODES: Readonly<Record<string, RegExp>> = {
+  AR: /^(?:[A-HJ-NP-Z]\d{4}[A-Z]{3}|\d{4})$/i,
+  AT: /^\d{4}$/,
+  AU: /^\d{4}$/,
+  BE: /^\d{4}$/,
+  BR: /^\d{5}-?\d{3}$/,
+  CA: /^[ABCEGHJ-NPRSTVXY]\d[ABCEGHJ-NPRSTV-Z] ?\d[ABCEGHJ-NPRSTV-Z]\d$/i,
+  CH: /^\d{4}$/,
+  CN: /^\d{6}$/,
+  CZ: /^\d{3} ?\d{2}$/,
+  DE: /^\d{5}$/,
+  DK: /^\d{4}$/,
+  ES: /^(?:0[1-9]|[1-4]\d|5[0-2])\d{3}$/,
+  FI: /^\d{5}$/,
+  FR: /^\d{5}$/,
+  GB: /^(?:GIR ?0AA|[A-Z]{1,2}\d[A-Z\d]? ?\d[A-Z]{2})$/i,
+  GR: /^\d{3} ?\d{2}$/,
+  HU: /^\d{4}$/,
+  IE: /^(?:[AC-FHKNPRTV-Y]\d{2}|D6W) ?[0-9AC-FHKNPRTV-Y]{4}$/i,
+  IL: /^\d{7}$/,
+  IN: /^[1-9]\d{5}$/,
+  IT: /^\d{5}$/,
+  JP: /^\d{3}-?\d{4}$/,
+  KR: /^\d{5}$/,
+  MX: /^\d{5}$/,
+  NL: /^[1-9]\d{3} ?[A-Z]{2}$/i,
+  NO: /^\d{4}$/,
+  NZ: /^\d{4}$/,
+  PL: /^\d{2}-\d{3}$/,
+  PT: /^\d{4}-\d{3}$/,
+  RU: /^\d{6}$/,
+  SE: /^\d{3} ?\d{2}$/,
+  SG: /^\d{6}$/,
+  SK: /^\d{3} ?\d{2}$/,
+  TR: /^\d{5}$/,
+  US: /^\d{5}(?:-\d{4})?$/,
+  ZA: /^\d{4}$/,
+};
@@ -1,4 +1,10 @@
 import type { StandardJSONSchemaV1, StandardSchemaV1 } from "@standard-schema/spec";
+import {
+  COUNTRY_CODES_ALPHA2,
+  COUNTRY_CODES_ALPHA3,
+  CURRENCY_CODES,
+  POSTAL_CODES,
+} from "./codes";
 
 type SchemaPrimitive = "string" | "number" | "boolean" | "any";
 
@@ -8,6 +14,12 @@ interface SchemaLike {
 
 type Simplify<T> = T extends any ? { [K in keyof T]: T[K] } : never;
 
+type DeepPartial<T> = T extends any[]
+  ? DeepPartial<T[number]>[]
+  : T extends Record<string, unknown>
+    ? { [K in keyof T]?: DeepPartial<T[K]> }
+    : T;
+
 type RequiredKeys<S> = {
   [K in keyof S]: S[K] extends OptionalSchema<any, any> ? never : K;
 }[keyof S];
@@ -63,6 +75,169 @@ type InferSchema<S> =
 
 type SchemaDefinition = SchemaLike;
 
+export type Brand<B extends string> = { readonly __brand: B };
+
+export type IssueCode =
+  | "invalid_type"
+  | "invalid_literal"
+  | "invalid_string"
+  | "invalid_date"
+  | "invalid_union_discriminator"
+  | "too_small"
+  | "too_big"
+  | "not_unique"
+  | "not_sorted"
+  | "unrecognized_keys"
+  | "not_multiple_of"
+  | "empty_string"
+  | "recursion_limit"
+  | "custom";
+
+/** Standard Schema issue with a machine-readable `code` */
+export interface ValidationIssue extends StandardSchemaV1.Issue {
+  readonly code: IssueCode;
+  /** Expected type, set on `invalid_type` issues */
+  readonly expected?: string;
+  /** Runtime type of the rejected value, set on `invalid_type` issues */
+  readonly received?: string;
+}
+
+/** Issue reported from a `superRefine()` callback, with a path relative to the refined value */
+export interface RefinementIssue {
+  message: string;
+  path?: PropertyKey[];
+  /** Defaults to `"custom"` */
+  code?: IssueCode;
+}
+
+export interface RefinementContext {
+  addIssue(issue: RefinementIssue): void;
+}
+
+export type ValidationResult<O> =
+  | StandardSchemaV1.SuccessResult<O>
+  | { readonly issues: ReadonlyArray<ValidationIssue> };
+
+export type SafeParseResult<O> =
+  | { success: true; data: O }
+  | { success: false; error: { issues: ReadonlyArray<ValidationIssue> } };
+
+/** Error thrown by `parse()`, carrying the validation issues */
+export class ValidationError extends Error {
+  readonly issues: ReadonlyArray<ValidationIssue>;
+
+  constructor(issues: ReadonlyArray<ValidationIssue>) {
+    super(issues[0]?.message ?? "Validation failed");
+    this.name = "ValidationError";
+    this.issues = issues;
+  }
+}
+
+/** Issues split into root-level messages and messages per top-level field */
+export interface FlattenedIssues {
+  formErrors: string[];
+  fieldErrors: Record<string, string[]>;
+}
+
+/** Nested issue tree mirroring the shape of the validated value */
+export type FormattedIssues = { _errors: string[] } & { [key: string]: FormattedIssues };
+
+function segmentKey(segment: PropertyKey | StandardSchemaV1.PathSegment): string {
+  return String(typeof segment === "object" ? segment.key : segment);
+}
+
+/**
+ * Group issue messages by top-level field, e.g. for form libraries
+ * @param {ReadonlyArray<StandardSchemaV1.Issue>} issues - Issues from a failed validation
+ * @returns {FlattenedIssues} Root messages and messages keyed by field
+ */
+export function flattenIssues(issues: ReadonlyArray<StandardSchemaV1.Issue>): FlattenedIssues {
+  const flattened: FlattenedIssues = { formErrors: [], fieldErrors: {} };
+  for (const issue of issues) {
+    const segment = issue.path?.[0];
+    if (segment === undefined) {
+      flattened.formErrors.push(issue.message);
+      continue;
+    }
+    const key = segmentKey(segment);
+    (flattened.fieldErrors[key] ??= []).push(issue.message);
+  }
+  return flattened;
+}
+
+/**
+ * Build a nested tree of issue messages, with `_errors` at every level
+ * @param {ReadonlyArray<StandardSchemaV1.Issue>} issues - Issues from a failed validation
+ * @returns {FormattedIssues} Issue tree mirroring the value shape
+ */
+export function formatIssues(issues: ReadonlyArray<StandardSchemaV1.Issue>): FormattedIssues {
+  const root = { _errors: [] } as unknown as FormattedIssues;
+  for (const issue of issues) {
+    let node = root;
+    for (const segment of issue.path ?? []) {
+      const key = segmentKey(segment);
+      node[key] ??= { _errors: [] } as unknown as FormattedIssues;
+      node = node[key];
+    }
+    node._errors.push(issue.message);
+  }
+  return root;
+}
+
+export interface ValidationOptions {
+  /** Stop at the first issue instead of collecting every issue */
+  abortEarly?: boolean;
+  /** Nesting limit for recursive `h.lazy()` schemas, 256 by default */
+  maxDepth?: number;
+  /** Stop collecting issues once this many are found, 1000 by default */
+  maxIssues?: number;
+}
+
+export interface ValidateManyOptions extends ValidationOptions {
+  /** Stop after the first item that fails */
+  bail?: boolean;
+  /** Return only the failing items, tagged with their index */
+  failuresOnly?: boolean;
+}
+
+/** Failing item reported by `validateMany()` with `failuresOnly` */
+export interface ValidateManyFailure {
+  index: number;
+  issues: ReadonlyArray<ValidationIssue>;
+}
+
+/** Structured values behind a built-in issue message */
+export interface IssueDetails {
+  expected?: string;
+  received?: string;
+  min?: number | bigint | string;
+  max?: number | bigint | string;
+  /** Step a number must be aligned to, set on `not_multiple_of` issues */
+  multipleOf?: number;
+}
+
+/** Issue passed to an error map, with its default `message` */
+export interface ErrorMapIssue extends IssueDetails {
+  code: IssueCode;
+  path: ReadonlyArray<PropertyKey | StandardSchemaV1.PathSegment>;
+  message: string;
+}
+
+/** Returns a replacement message, or `undefined` to keep the default */
+export type ErrorMap = (issue: ErrorMapIssue) => string | undefined;
+
+let errorMap: ErrorMap | undefined;
+
+const ISSUE_DETAILS = Symbol("issueDetails");
+
+/**
+ * Set a global error map to translate or reword built-in issue messages
+ * @param {ErrorMap | undefined} map - Message mapper, or `undefined` to restore the defaults
+ */
+export function setErrorMap(map: ErrorMap | undefined): void {
+  errorMap = map;
+}
+
 type CombinedStandardProps<I, O> = StandardSchemaV1.Props<I, O> & {
   readonly jsonSchema: StandardJSONSchemaV1.Converter;
 };
@@ -71,7 +246,14 @@ interface Schema<I, O> {
   readonly "~standard": CombinedStandardProps<I, O>;
   optional(): OptionalSchema<I, O | undefined>;
   null(): UnionSchema<I, O | null>;
-  nullable(): UnionSchema<I, O | null>;
+  nullable(): NullableSchema<I, O | null>;
+  nullish(): OptionalSchema<I, O | null | undefined>;
+  default(value: Exclude<O, undefined>): DefaultSchema<I, Exclude<O, undefined>>;
+  catch(value: O): CatchSchema<I, O>;
+  transform<T>(fn: (value: O) => T): TransformSchema<I, T>;
+  pipe<T>(next: Schema<any, T>): PipeSchema<I, T>;
+  and<T>(other: Schema<any, T>): IntersectionSchema<I, O & T>;
+  brand<B extends string>(name: B): BrandedSchema<I, O & Brand<B>>;
   enum<V extends O & (string | number | boolean), Values extends readonly [V, ...V[]]>(
     values: Values,
   ): UnionSchema<I, Values[number]>;
@@ -79,736 +261,4089 @@ interface Schema<I, O> {
   instanceOf<C extends new (...args: any[]) => any>(
     constructor: C,
   ): InstanceOfSchema<I, InstanceType<C>>;
+  getType(): string;
+  isOptional(): boolean;
   jsonSchema: any;
   readonly inferred: O;
   schema: Schema<I, O>;
 }
 
 export abstract class BaseSchema<I, O> implements Schema<I, O> {
-  abstract readonly "~standard": CombinedStandardProps<I, O>;
   jsonSchema: any = {};
   get inferred(): O {
     return null as unknown as O;
   }
   schema: Schema<I, O> = this;
   protected _coerce = false;
+  protected _description?: string;
+  protected _refinements: { check: (value: O) => unknown; message: string }[] = [];
+  protected _superRefinements: ((value: O, context: RefinementContext) => void)[] = [];
+  protected _asyncRefinements: { check: (value: O) => Promise<unknown>; message: string }[] = [];
+  protected _preprocess?: (value: unknown) => unknown;
 
-  coerce(): this {
-    this._coerce = true;
-    return this;
-  }
-
-  optional(): OptionalSchema<I, O | undefined> {
-    return new OptionalSchema<I, O>(this);
-  }
+  declare readonly "~standard": CombinedStandardProps<I, O>;
 
-  null(): UnionSchema<I, O | null> {
-    return new UnionSchema<I, O | null>(this, new NullSchemaType() as any);
+  constructor() {
+    defineStandard(this);
   }
 
   /**
-   * Allows the value to be `null` (alias for {@link null})
-   * @returns {UnionSchema<I, O | null>} Schema that also accepts null
+   * Build the Standard Schema interface bound to this instance
+   * @returns {CombinedStandardProps<I, O>} Standard Schema props
    */
-  nullable(): UnionSchema<I, O | null> {
-    return this.null();
+  protected _standard(): CombinedStandardProps<I, O> {
+    return {
+      version: 1,
+      vendor: "h-schema",
+      jsonSchema: {
+        input: () => cloneJsonSchema(this.jsonSchema),
+        output: () => cloneJsonSchema(this.jsonSchema),
+      },
+      validate: (value: unknown, options?: StandardSchemaV1.Options) => this._run(value, options),
+      types: {
+        input: {} as I,
+        output: {} as O,
+      },
+    };
   }
 
-  enum<V extends O & (string | number | boolean), Values extends readonly [V, ...V[]]>(
-    values: Values,
-  ): UnionSchema<I, Values[number]> {
-    const literalSchemas = values.map((value) => new LiteralSchema<I, V>(value));
-    return new UnionSchema<I, Values[number]>(...literalSchemas);
-  }
+  protected abstract _validate(
+    value: unknown,
+    options?: StandardSchemaV1.Options,
+  ): ValidationResult<O>;
 
-  array(): ArraySchema<I, O[]> {
-    return new ArraySchema<I, O[]>(this);
+  private _run(
+    value: unknown,
+    options?: StandardSchemaV1.Options,
+  ): ValidationResult<O> | Promise<ValidationResult<O>> {
+    if (options?.libraryOptions && "pending" in options.libraryOptions) {
+      return this._check(value, options);
+    }
+    const pending: PendingCheck[] = [];
+    const result = this._check(value, { libraryOptions: { ...options?.libraryOptions, pending } });
+    if (result.issues) {
+      return localizeIssues(result);
+    }
+    return pending.length === 0 ? result : settlePending(result, pending);
   }
 
-  instanceOf<C extends new (...args: any[]) => any>(
-    constructor: C,
-  ): InstanceOfSchema<I, InstanceType<C>> {
-    return new InstanceOfSchema<I, InstanceType<C>>(this, constructor);
+  private _check(value: unknown, options?: StandardSchemaV1.Options): ValidationResult<O> {
+    if (this._preprocess) {
+      try {
+        value = this._preprocess(value);
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error);
+        return { issues: [{ message, code: "custom" }] };
+      }
+    }
+    const result = this._validate(value, options);
+    if (result.issues) {
+      const limit = maxIssues(options);
+      return result.issues.length > limit ? { issues: result.issues.slice(0, limit) } : result;
+    }
+    const issues: ValidationIssue[] = [];
+    for (const refinement of this._refinements) {
+      if (!refinement.check(result.value)) {
+        issues.push({ message: refinement.message, code: "custom" });
+        if (isAbortEarly(options)) {
+          break;
+        }
+      }
+    }
+    const context: RefinementContext = {
+      addIssue: ({ message, path, code = "custom" }) => {
+        if (!hasEnoughIssues(issues, options)) {
+          issues.push(path?.length ? { message, path, code } : { message, code });
+        }
+      },
+    };
+    for (const refinement of this._superRefinements) {
+      if (hasEnoughIssues(issues, options)) {
+        break;
+      }
+      refinement(result.value, context);
+    }
+    if (issues.length > 0) {
+      return { issues };
+    }
+    if (this._asyncRefinements.length > 0) {
+      const pending = pendingChecks(options);
+      if (!pending) {
+        return {
+          issues: [{ message: "Async refinements require validateAsync()", code: "custom" }],
+        };
+      }
+      pending.push({
+        path: [],
+        issues: runAsyncRefinements(this._asyncRefinements, result.value, isAbortEarly(options)),
+      });
+    }
+    return result;
   }
-}
 
-function validatePrimitive(schema: SchemaPrimitive, value: unknown): boolean {
-  if (typeof value === "string" && schema === "string") {
-    return true;
-  }
-  if (typeof value === "number" && schema === "number" && !Number.isNaN(value)) {
-    return true;
+  /**
+   * Validate a value with library options
+   * @param {unknown} value - Value to validate
+   * @param {ValidationOptions} options - Set `abortEarly` to stop at the first issue
+   * @returns {ValidationResult<O>} Validation result
+   */
+  validateWithOptions(value: unknown, options: ValidationOptions = {}): ValidationResult<O> {
+    return localizeIssues(this._check(value, { libraryOptions: { ...options, pending: null } }));
   }
-  if (typeof value === "boolean" && schema === "boolean") {
-    return true;
+
+  /**
+   * Validate a value that sits inside a larger document, rooting issue paths at its location
+   * @param {unknown} value - Value to validate
+   * @param {PropertyKey[]} path - Location of the value, e.g. `["body", "user"]`
+   * @param {ValidationOptions} options - Set `abortEarly` to stop at the first issue
+   * @returns {ValidationResult<O>} Validation result with prefixed issue paths
+   */
+  validateAt(
+    value: unknown,
+    path: PropertyKey[],
+    options: ValidationOptions = {},
+  ): ValidationResult<O> {
+    const result = this.validateWithOptions(value, options);
+    if (!result.issues || path.length === 0) {
+      return result;
+    }
+    return {
+      issues: result.issues.map((issue) => ({ ...issue, path: [...path, ...(issue.path ?? [])] })),
+    };
   }
-  return false;
-}
 
-export class StringSchemaType extends BaseSchema<unknown, string> {
-  readonly type: SchemaPrimitive = "string";
-  private _validateDate = false;
-  private _validateUUID = false;
-  private _validateRegex = false;
-  private _validateEmail = false;
-  private _validatePhone = false;
-  private _validateDomain = false;
-  private _requireHttpOrHttps = false;
-  private _minLength?: number;
-  private _maxLength?: number;
+  /**
+   * Validate every item of a batch, e.g. the rows of an import
+   * @param {unknown[]} values - Items to validate
+   * @param {ValidateManyOptions} options - Set `bail` or `failuresOnly` to trim the output
+   * @returns {ValidationResult<O>[] | ValidateManyFailure[]} Per-item results, or failures only
+   */
+  validateMany(
+    values: unknown[],
+    options: ValidateManyOptions & { failuresOnly: true },
+  ): ValidateManyFailure[];
+  validateMany(values: unknown[], options?: ValidateManyOptions): ValidationResult<O>[];
+  validateMany(
+    values: unknown[],
+    options: ValidateManyOptions = {},
+  ): ValidationResult<O>[] | ValidateManyFailure[] {
+    const { bail, failuresOnly, ...validationOptions } = options;
+    const results: ValidationResult<O>[] = [];
+    const failures: ValidateManyFailure[] = [];
+    for (let index = 0; index < values.length; index++) {
+      const result = this.validateWithOptions(values[index], validationOptions);
+      if (!failuresOnly) {
+        results.push(result);
+      } else if (result.issues) {
+        failures.push({ index, issues: result.issues });
+      }
+      if (bail && result.issues) {
+        break;
+      }
+    }
+    return failuresOnly ? failures : results;
+  }
 
-  constructor() {
-    super();
-    this.jsonSchema = { type: "string" };
+  /**
+   * Validate a value, awaiting any async refinements
+   * @param {unknown} value - Value to validate
+   * @param {ValidationOptions} options - Set `abortEarly` to stop at the first issue
+   * @returns {Promise<ValidationResult<O>>} Validation result
+   */
+  async validateAsync(
+    value: unknown,
+    options: ValidationOptions = {},
+  ): Promise<ValidationResult<O>> {
+    const pending: PendingCheck[] = [];
+    const result = this._check(value, { libraryOptions: { ...options, pending } });
+    return result.issues ? localizeIssues(result) : settlePending(result, pending);
   }
 
-  primitive(): SchemaPrimitive {
-    return this.type;
+  /**
+   * Validate a value and return it, throwing on failure
+   * @param {unknown} value - Value to validate
+   * @returns {O} Parsed value
+   * @throws {ValidationError} When validation fails, with the issues on `.issues`
+   */
+  parse(value: unknown): O {
+    const result = this.validateWithOptions(value);
+    if (result.issues) {
+      throw new ValidationError(result.issues);
+    }
+    return result.value;
   }
 
-  minLength(n: number): StringSchemaType {
-    const schema = new StringSchemaType();
-    Object.assign(schema, this);
-    schema._minLength = n;
-    schema.jsonSchema = {
-      ...this.jsonSchema,
-      minLength: n,
-    };
-    return schema;
+  /**
+   * Validate a value without throwing
+   * @param {unknown} value - Value to validate
+   * @returns {SafeParseResult<O>} `{ success: true, data }` or `{ success: false, error }`
+   */
+  safeParse(value: unknown): SafeParseResult<O> {
+    const result = this.validateWithOptions(value);
+    if (result.issues) {
+      return { success: false, error: { issues: result.issues } };
+    }
+    return { success: true, data: result.value };
   }
 
-  maxLength(n: number): StringSchemaType {
-    const schema = new StringSchemaType();
+  protected _clone(): this {
+    const schema = Object.create(Object.getPrototypeOf(this)) as this;
+    defineStandard(schema);
     Object.assign(schema, this);
-    schema._maxLength = n;
-    schema.jsonSchema = {
-      ...this.jsonSchema,
-      maxLength: n,
-    };
+    schema.schema = schema;
     return schema;
   }
 
-  date(): StringSchemaType {
-    const schema = new StringSchemaType();
-    Object.assign(schema, this);
-    schema._validateDate = true;
-    schema.jsonSchema = { ...this.jsonSchema, format: "date" };
+  /**
+   * Add a custom check that runs after the schema's own validation succeeds
+   * @param {(value: O) => unknown} check - Predicate receiving the parsed value
+   * @param {string} [message] - Issue message used when the predicate returns a falsy value
+   * @returns {this} Schema with the refinement applied
+   */
+  refine(check: (value: O) => unknown, message = "Invalid value"): this {
+    const schema = this._clone();
+    schema._refinements = [...this._refinements, { check, message }];
     return schema;
   }
 
-  uuid(): StringSchemaType {
-    const schema = new StringSchemaType();
-    schema._validateUUID = true;
-    schema.jsonSchema = { ...this.jsonSchema, format: "uuid" };
+  /**
+   * Add a custom check that can report any number of issues, each with its own path
+   * @param {(value: O, context: RefinementContext) => void} check - Callback receiving the parsed
+   * value and a context whose `addIssue()` records an issue
+   * @returns {this} Schema with the refinement applied
+   */
+  superRefine(check: (value: O, context: RefinementContext) => void): this {
+    const schema = this._clone();
+    schema._superRefinements = [...this._superRefinements, check];
     return schema;
   }
 
-  regex(regex: RegExp): StringSchemaType {
-    const schema = new StringSchemaType();
-    schema._validateRegex = true;
-    schema.jsonSchema = { ...this.jsonSchema, pattern: regex.source };
+  /**
+   * Transform the raw input before any other validation runs
+   * @param {(value: unknown) => unknown} fn - Function receiving the unvalidated input
+   * @returns {this} Schema with the preprocessing step applied
+   */
+  preprocess(fn: (value: unknown) => unknown): this {
+    const schema = this._clone();
+    schema._preprocess = fn;
     return schema;
   }
 
-  email(): StringSchemaType {
-    const schema = new StringSchemaType();
-    schema._validateEmail = true;
-    schema.jsonSchema = { ...this.jsonSchema, format: "email" };
+  /**
+   * Attach a human-readable description, emitted as `description` in the JSON Schema
+   * @param {string} text - Description of the value
+   * @returns {this} Schema with the description applied
+   */
+  describe(text: string): this {
+    const schema = this._clone();
+    schema._description = text;
+    schema.jsonSchema = { ...this.jsonSchema, description: text };
     return schema;
   }
 
-  phone(): StringSchemaType {
-    const schema = new StringSchemaType();
-    schema._validatePhone = true;
-    schema.jsonSchema = { ...this.jsonSchema, format: "phone" };
+  /**
+   * Merge JSON Schema annotations such as `title`, `example` or vendor extensions
+   * @param {Record<string, unknown>} annotations - Keys to add; `type` and `format` are ignored
+   * @returns {this} Schema with the annotations applied
+   */
+  meta(annotations: Record<string, unknown>): this {
+    const { type: _type, format: _format, ...rest } = annotations;
+    const schema = this._clone();
+    schema.jsonSchema = { ...this.jsonSchema, ...rest };
     return schema;
   }
 
-  domain(requireHttpOrHttps = true): StringSchemaType {
-    const schema = new StringSchemaType();
-    schema._validateDomain = true;
-    schema.jsonSchema = { ...this.jsonSchema, format: "domain" };
-    schema._requireHttpOrHttps = requireHttpOrHttps;
+  /**
+   * Name the schema so `h.getJsonSchema()` emits it once under `$defs` and references it
+   * @param {string} name - Definition name, e.g. `"Address"`
+   * @returns {this} Schema carrying the definition name
+   */
+  id(name: string): this {
+    const schema = this._clone();
+    const definition = { ...this.jsonSchema };
+    schema.jsonSchema = { ...definition, [SCHEMA_ID]: { name, definition } };
     return schema;
   }
 
-  readonly "~standard": CombinedStandardProps<unknown, string> = {
-    version: 1,
-    vendor: "h-schema",
-    jsonSchema: {
-      input: () => this.jsonSchema,
-      output: () => this.jsonSchema,
-    },
-    validate: (value: unknown) => {
-      if (this._coerce && typeof value !== "string") {
-        value = String(value);
-      }
-
-      if (typeof value !== "string") {
-        return {
-          issues: [{ message: `Expected string, received ${typeof value}` }],
-        };
-      }
-
-      if (this._minLength !== undefined && value.length < this._minLength) {
-        return { issues: [{ message: `String shorter than ${this._minLength}` }] };
-      }
-
-      if (this._maxLength !== undefined && value.length > this._maxLength) {
-        return { issues: [{ message: `String longer than ${this._maxLength}` }] };
-      }
+  get description(): string | undefined {
+    return this._description;
+  }
 
-      if (this._validateUUID && !this._isValidUUID(value)) {
-        return {
-          issues: [{ message: "Invalid UUID format" }],
-        };
-      }
+  /**
+   * Get the JSON Schema document for a draft, with its `$schema` URI and keywords
+   * @param {JsonSchemaDraft} draft - `"draft-2020-12"` (default) or `"draft-07"`
+   * @returns {Record<string, unknown>} JSON Schema document
+   */
+  toJsonSchema(draft: JsonSchemaDraft = "draft-2020-12"): Record<string, unknown> {
+    return { $schema: JSON_SCHEMA_URIS[draft], ...buildJsonSchema(this, draft) };
+  }
 
-      if (this._validateRegex && !this._isValidRegex(value)) {
-        return {
-          issues: [{ message: "Invalid regex format" }],
-        };
-      }
+  /**
+   * Get the kind of value this schema validates, e.g. `"string"` or `"object"`
+   * @returns {string} Type name, or `"unknown"` for unions and other composite schemas
+   */
+  getType(): string {
+    const type = this.jsonSchema.type;
+    return typeof type === "string" ? type : "unknown";
+  }
 
-      if (this._validateEmail && !this._isValidEmail(value)) {
-        return {
-          issues: [{ message: "Invalid email format" }],
-        };
-      }
+  /**
+   * Whether the value may be omitted from an object
+   * @returns {boolean} True for optional schemas and schemas with a default
+   */
+  isOptional(): boolean {
+    return false;
+  }
 
-      if (this._validatePhone && !this._isValidPhone(value)) {
-        return {
-          issues: [{ message: "Invalid phone number format" }],
-        };
-      }
+  /**
+   * Add an async check, run by `validateAsync()` after synchronous validation succeeds
+   * @param {(value: O) => Promise<unknown>} check - Async predicate receiving the parsed value
+   * @param {string} [message] - Issue message used when the predicate resolves to a falsy value
+   * @returns {this} Schema with the async refinement applied
+   */
+  refineAsync(check: (value: O) => Promise<unknown>, message = "Invalid value"): this {
+    const schema = this._clone();
+    schema._asyncRefinements = [...this._asyncRefinements, { check, message }];
+    return schema;
+  }
 
-      if (this._validateDomain && !this._isValidDomain(value)) {
-        return {
-          issues: [{ message: "Invalid domain format" }],
-        };
-      }
+  coerce(): this {
+    const schema = this._clone();
+    schema._coerce = true;
+    return schema;
+  }
 
-      if (this._validateDate && !this._isValidDate(value)) {
-        return { issues: [{ message: "Invalid date format" }] };
-      }
+  optional(): OptionalSchema<I, O | undefined> {
+    return new OptionalSchema<I, O>(this);
+  }
 
-      return { value };
-    },
-    types: {
-      input: {} as unknown,
-      output: {} as string,
-    },
-  };
+  null(): UnionSchema<I, O | null> {
+    return new UnionSchema<I, O | null>(this, new NullSchemaType() as any);
+  }
 
-  private _isValidDate(value: string): boolean {
-    const date = new Date(value);
-    return !Number.isNaN(date.getTime());
+  /**
+   * Allows the value to be `null` while still requiring it to be present
+   * @returns {NullableSchema<I, O | null>} Schema that also accepts null
+   */
+  nullable(): NullableSchema<I, O | null> {
+    return new NullableSchema<I, O>(this);
   }
 
-  private _isValidUUID(value: string): boolean {
-    const uuidRegex = /^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$/i;
-    return uuidRegex.test(value);
+  /**
+   * Allows the value to be either `null` or `undefined`
+   * @returns {OptionalSchema<I, O | null | undefined>} Schema that also accepts null and undefined
+   */
+  nullish(): OptionalSchema<I, O | null | undefined> {
+    return new OptionalSchema<I, O | null>(this.nullable());
   }
 
-  private _isValidRegex(value: string): boolean {
-    return new RegExp(this.jsonSchema.pattern!).test(value);
+  /**
+   * Use a default value when the input is `undefined`
+   * @param {Exclude<O, undefined>} value - Value validated in place of missing input
+   * @returns {DefaultSchema<I, Exclude<O, undefined>>} Schema that fills in missing input
+   */
+  default(value: Exclude<O, undefined>): DefaultSchema<I, Exclude<O, undefined>> {
+    return new DefaultSchema<I, Exclude<O, undefined>>(this, value);
   }
 
-  private _isValidEmail(value: string): boolean {
-    const emailRegex = /^[^\s@]+@[^\s@]+\.[^\s@]+$/;
-    return emailRegex.test(value);
+  /**
+   * Return a fallback value instead of failing when validation produces issues
+   * @param {O} value - Fallback value
+   * @returns {CatchSchema<I, O>} Schema that never fails
+   */
+  catch(value: O): CatchSchema<I, O> {
+    return new CatchSchema<I, O>(this, value);
   }
 
-  private _isValidPhone(value: string): boolean {
-    const phoneRegex = /^\+?[0-9]{7,15}$/;
-    return phoneRegex.test(value);
+  /**
+   * Map the parsed value after validation and refinements succeed.
+   * The JSON Schema keeps describing the input, so it is unaffected.
+   * @param {(value: O) => T} fn - Function receiving the parsed value
+   * @returns {TransformSchema<I, T>} Schema producing the transformed value
+   */
+  transform<T>(fn: (value: O) => T): TransformSchema<I, T> {
+    return new TransformSchema<I, T>(this, fn);
   }
 
-  private _isValidDomain(value: string): boolean {
-    let domainRegex = /^[a-z0-9]+([-.]{1}[a-z0-9]+)*\.[a-z]{2,6}$/;
-    if (this._requireHttpOrHttps) {
-      domainRegex = /^https?:\/\/[a-z0-9]+([-.]{1}[a-z0-9]+)*\.[a-z]{2,6}$/;
-    }
-    return domainRegex.test(value);
+  /**
+   * Feed the parsed value into another schema
+   * @param {Schema<any, T>} next - Schema validating this schema's output
+   * @returns {PipeSchema<I, T>} Schema producing the output of `next`
+   */
+  pipe<T>(next: Schema<any, T>): PipeSchema<I, T> {
+    return new PipeSchema<I, T>(this, next);
   }
-}
 
-export class NumberSchemaType extends BaseSchema<unknown, number> {
-  readonly type: SchemaPrimitive = "number";
-  private _min?: number;
-  private _max?: number;
+  /**
+   * Require the value to match both this schema and another one
+   * @param {Schema<any, T>} other - Schema merged on top of this one
+   * @returns {IntersectionSchema<I, O & T>} Intersection schema
+   */
+  and<T>(other: Schema<any, T>): IntersectionSchema<I, O & T> {
+    return new IntersectionSchema<I, O & T>(this, other);
+  }
 
-  constructor() {
-    super();
-    this.jsonSchema = { type: "number" };
+  /**
+   * Tag the output type with a nominal brand; validation is unchanged
+   * @param {B} name - Brand name, emitted as `x-brand` in the JSON Schema
+   * @returns {BrandedSchema<I, O & Brand<B>>} Branded schema
+   */
+  brand<B extends string>(name: B): BrandedSchema<I, O & Brand<B>> {
+    return new BrandedSchema<I, O & Brand<B>>(this, name);
   }
 
-  primitive(): SchemaPrimitive {
-    return this.type;
+  enum<V extends O & (string | number | boolean), Values extends readonly [V, ...V[]]>(
+    values: Values,
+  ): UnionSchema<I, Values[number]> {
+    const literalSchemas = values.map((value) => new LiteralSchema<I, V>(value));
+    return new UnionSchema<I, Values[number]>(...literalSchemas);
   }
 
-  min(n: number): NumberSchemaType {
-    const schema = new NumberSchemaType();
-    Object.assign(schema, this);
-    schema._min = n;
-    schema.jsonSchema = {
-      ...this.jsonSchema,
-      minimum: n,
-    };
-    return schema;
+  array(): ArraySchema<I, O[]> {
+    return new ArraySchema<I, O[]>(this);
   }
 
-  max(n: number): NumberSchemaType {
-    const schema = new NumberSchemaType();
-    Object.assign(schema, this);
-    schema._max = n;
-    schema.jsonSchema = {
-      ...this.jsonSchema,
-      maximum: n,
-    };
-    return schema;
+  instanceOf<C extends new (...args: any[]) => any>(
+    constructor: C,
+  ): InstanceOfSchema<I, InstanceType<C>> {
+    return new InstanceOfSchema<I, InstanceType<C>>(this, constructor);
   }
+}
 
-  readonly "~standard": CombinedStandardProps<unknown, number> = {
-    version: 1,
-    vendor: "h-schema",
-    jsonSchema: {
-      input: () => this.jsonSchema,
-      output: () => this.jsonSchema,
-    },
-    validate: (value: unknown) => {
-      if (this._coerce && typeof value !== "number") {
-        const coerced = Number(value);
-        if (!Number.isNaN(coerced)) {
-          value = coerced;
-        }
-      }
-      if (typeof value !== "number" || Number.isNaN(value)) {
-        return {
-          issues: [{ message: `Expected number, received ${typeof value}` }],
-        };
-      }
-      if (this._min !== undefined && value < this._min) {
-        return { issues: [{ message: `Number less than ${this._min}` }] };
-      }
-      if (this._max !== undefined && value > this._max) {
-        return { issues: [{ message: `Number greater than ${this._max}` }] };
-      }
-      return { value };
-    },
-    types: {
-      input: {} as unknown,
-      output: {} as number,
-    },
-  };
+function isAbortEarly(options?: StandardSchemaV1.Options): boolean {
+  return options?.libraryOptions?.abortEarly === true;
 }
 
-export class BooleanSchemaType extends BaseSchema<unknown, boolean> {
-  readonly type: SchemaPrimitive = "boolean";
+const MAX_DEPTH = 256;
+const MAX_ISSUES = 1000;
 
-  constructor() {
-    super();
-    this.jsonSchema = { type: "boolean" };
-  }
+function maxDepth(options?: StandardSchemaV1.Options): number {
+  const depth = options?.libraryOptions?.maxDepth;
+  return typeof depth === "number" ? depth : MAX_DEPTH;
+}
 
-  primitive(): SchemaPrimitive {
-    return this.type;
+function maxIssues(options?: StandardSchemaV1.Options): number {
+  const limit = options?.libraryOptions?.maxIssues;
+  return typeof limit === "number" ? limit : MAX_ISSUES;
+}
+
+/** Whether a collection loop should stop, on the first issue or once `maxIssues` is reached */
+function hasEnoughIssues(issues: ValidationIssue[], options?: StandardSchemaV1.Options): boolean {
+  return issues.length > 0 && (isAbortEarly(options) || issues.length >= maxIssues(options));
+}
+
+/** Async refinement result collected during a validation pass, relative to `path` */
+interface PendingCheck {
+  path: PropertyKey[];
+  issues: Promise<ValidationIssue[]>;
+}
+
+function pendingChecks(options?: StandardSchemaV1.Options): PendingCheck[] | undefined {
+  const pending = options?.libraryOptions?.pending;
+  return Array.isArray(pending) ? pending : undefined;
+}
+
+function pendingCount(options?: StandardSchemaV1.Options): number {
+  return pendingChecks(options)?.length ?? 0;
+}
+
+function prefixPending(
+  options: StandardSchemaV1.Options | undefined,
+  start: number,
+  prefix: PropertyKey[],
+): void {
+  const pending = pendingChecks(options) ?? [];
+  for (let i = start; i < pending.length; i++) {
+    pending[i]!.path = [...prefix, ...pending[i]!.path];
   }
+}
 
-  readonly "~standard": CombinedStandardProps<unknown, boolean> = {
-    version: 1,
-    vendor: "h-schema",
-    jsonSchema: {
-      input: () => this.jsonSchema,
-      output: () => this.jsonSchema,
-    },
-    validate: (value: unknown) => {
-      if (this._coerce && typeof value !== "boolean") {
-        if (value === "true" || value === 1 || value === "1") {
-          value = true;
-        } else if (value === "false" || value === 0 || value === "0") {
-          value = false;
-        }
-      }
-      if (typeof value !== "boolean") {
-        return {
-          issues: [{ message: `Expected boolean, received ${typeof value}` }],
-        };
-      }
-      return { value };
-    },
-    types: {
-      input: {} as unknown,
-      output: {} as boolean,
-    },
-  };
+type DetailedIssue = ValidationIssue & { [ISSUE_DETAILS]?: IssueDetails };
+
+/** Runtime type of a value for issue messages, telling `null`, arrays and `NaN` apart */
+function typeName(value: unknown): string {
+  if (value === null) {
+    return "null";
+  }
+  if (Array.isArray(value)) {
+    return "array";
+  }
+  if (typeof value === "number" && Number.isNaN(value)) {
+    return "NaN";
+  }
+  return typeof value;
 }
 
-export class AnySchemaType extends BaseSchema<unknown, any> {
-  readonly type: SchemaPrimitive = "any";
-  readonly "~standard": CombinedStandardProps<unknown, any> = {
-    version: 1,
-    vendor: "h-schema",
-    jsonSchema: {
-      input: () => ({}),
-      output: () => ({}),
-    },
-    validate: (value: unknown) => {
-      return { value };
-    },
-    types: {
-      input: {} as unknown,
-      output: {} as any,
-    },
-  };
+function typeMismatch(expected: string, value: unknown): ValidationIssue {
+  const received = typeName(value);
+  return builtinIssue("invalid_type", `Expected ${expected}, received ${received}`, {
+    expected,
+    received,
+  });
 }
 
-export class LiteralSchema<I, T extends string | number | boolean> extends BaseSchema<I, T> {
-  private readonly value: T;
+function builtinIssue(
+  code: IssueCode,
+  message: string,
+  details: IssueDetails = {},
+): ValidationIssue {
+  const { expected, received } = details;
+  const issue: ValidationIssue =
+    code === "invalid_type" && expected !== undefined && received !== undefined
+      ? { message, code, expected, received }
+      : { message, code };
+  if (!errorMap) {
+    return issue;
+  }
+  const detailed: DetailedIssue = { ...issue, [ISSUE_DETAILS]: details };
+  return detailed;
+}
 
-  constructor(value: T) {
-    super();
-    this.value = value;
-    this.jsonSchema = {
-      const: value,
-      type: typeof value as "string" | "number" | "boolean",
-    };
+function localizeIssues<R extends ValidationResult<any>>(result: R): R {
+  const map = errorMap;
+  if (!result.issues || !map) {
+    return result;
   }
+  const issues = result.issues.map((entry: DetailedIssue) => {
+    const { [ISSUE_DETAILS]: details, ...plain } = entry;
+    if (!details) {
+      return entry;
+    }
+    const path = entry.path ?? [];
+    const message = map({ ...details, code: entry.code, path, message: entry.message });
+    return { ...plain, message: message ?? entry.message };
+  });
+  return { ...result, issues };
+}
 
-  readonly "~standard": CombinedStandardProps<I, T> = {
-    version: 1,
-    vendor: "h-schema",
-    jsonSchema: {
-      input: () => this.jsonSchema,
-      output: () => this.jsonSchema,
-    },
-    validate: (value: unknown) => {
-      if (value !== this.value) {
-        return {
-          issues: [{ message: `Expected literal value ${this.value}, received ${value}` }],
-        };
+function discardPending(options: StandardSchemaV1.Options | undefined, start: number): void {
+  pendingChecks(options)?.splice(start);
+}
+
+async function runAsyncRefinements<O>(
+  refinements: { check: (value: O) => Promise<unknown>; message: string }[],
+  value: O,
+  abortEarly: boolean,
+): Promise<ValidationIssue[]> {
+  const issues: ValidationIssue[] = [];
+  for (const refinement of refinements) {
+    if (!(await refinement.check(value))) {
+      issues.push({ message: refinement.message, code: "custom" });
+      if (abortEarly) {
+        break;
       }
-      return { value: value as T };
-    },
-    types: {
-      input: {} as I,
-      output: {} as T,
+    }
+  }
+  return issues;
+}
+
+async function settlePending<O>(
+  result: StandardSchemaV1.SuccessResult<O>,
+  pending: PendingCheck[],
+): Promise<ValidationResult<O>> {
+  const settled = await Promise.all(
+    pending.map(async (check) =>
+      (await check.issues).map((issue) => {
+        const path = [...check.path, ...(issue.path ?? [])];
+        return path.length > 0 ? { ...issue, path } : issue;
+      }),
+    ),
+  );
+  const issues = settled.flat();
+  return issues.length > 0 ? { issues } : result;
+}
+
+/**
+ * Define `~standard` as an own enumerable property, like the class field it
+ * used to be, created once per instance and bound to it. Writes are ignored so
+ * builders copying fields with `Object.assign` keep their own binding.
+ */
+function defineStandard(schema: BaseSchema<any, any>): void {
+  let props: CombinedStandardProps<any, any> | undefined;
+  Object.defineProperty(schema, "~standard", {
+    configurable: true,
+    enumerable: true,
+    get: () => {
+      props ??= (schema as any)._standard();
+      return props;
     },
-  };
+    set: () => {},
+  });
 }
 
-export class OptionalSchema<I, O> extends BaseSchema<I, O | undefined> {
-  private readonly innerSchema: Schema<I, O>;
+/** Deep copy of a JSON Schema, so consumers can't mutate objects shared between schemas */
+function cloneJsonSchema<T>(json: T): T {
+  if (Array.isArray(json)) {
+    return json.map(cloneJsonSchema) as T;
+  }
+  if (!isPlainJson(json)) {
+    return json;
+  }
+  const copy: Record<PropertyKey, unknown> = { ...json };
+  for (const key of Object.keys(copy)) {
+    copy[key] = cloneJsonSchema(copy[key]);
+  }
+  const id = (json as { [SCHEMA_ID]?: SchemaId })[SCHEMA_ID];
+  if (id) {
+    // `collectDefinitions()` tells the named schema apart from sibling keys by identity
+    const definition: Record<string, unknown> = {};
+    for (const [key, value] of Object.entries(id.definition)) {
+      definition[key] = json[key] === value ? copy[key] : cloneJsonSchema(value);
+    }
+    copy[SCHEMA_ID] = { name: id.name, definition };
+  }
+  return copy as T;
+}
 
-  constructor(schema: Schema<I, O>) {
-    super();
-    this.innerSchema = schema;
-    this.jsonSchema = { ...schema.jsonSchema };
+function isPlainJson(value: unknown): value is Record<string, unknown> {
+  return (
+    typeof value === "object" && value !== null && Object.getPrototypeOf(value) === Object.prototype
+  );
+}
+
+function isPlainObject(value: unknown): value is Record<string, unknown> {
+  return typeof value === "object" && value !== null && !Array.isArray(value);
+}
+
+/** Order direction checked by `array().ascending()` / `descending()` */
+export type SortOrder = "ascending" | "descending";
+
+function compareNatural(a: any, b: any): number {
+  return a < b ? -1 : a > b ? 1 : 0;
+}
+
+function findUnsortedIndex(
+  values: unknown[],
+  order: SortOrder,
+  compare: (a: any, b: any) => number,
+): number {
+  for (let i = 1; i < values.length; i++) {
+    const result = compare(values[i - 1], values[i]);
+    if (order === "ascending" ? result > 0 : result < 0) {
+      return i;
+    }
   }
+  return -1;
+}
 
-  readonly "~standard": CombinedStandardProps<I, O | undefined> = {
-    version: 1,
-    vendor: "h-schema",
-    jsonSchema: {
-      input: () => this.jsonSchema,
-      output: () => this.jsonSchema,
-    },
-    validate: (value: unknown) => {
-      if (value === undefined || value === null) {
-        return { value: undefined };
+/**
+ * Index of the first element equal to an earlier one, or -1. Primitives and
+ * keys compare with SameValueZero, so `NaN` matches `NaN` but `null` and
+ * `undefined` stay distinct. Objects without a key function compare with
+ * {@link structurallyEqual}.
+ */
+function findDuplicateIndex(values: unknown[], key?: (value: any) => unknown): number {
+  const primitives = new Set<unknown>();
+  const objects: object[] = [];
+  for (let i = 0; i < values.length; i++) {
+    const value = key ? key(values[i]) : values[i];
+    if (typeof value === "object" && value !== null) {
+      if (objects.some((seen) => structurallyEqual(seen, value))) {
+        return i;
       }
+      objects.push(value);
+    } else {
+      if (primitives.has(value)) {
+        return i;
+      }
+      primitives.add(value);
+    }
+  }
+  return -1;
+}
 
-      const result = this.innerSchema["~standard"].validate(value);
-      return result;
-    },
-    types: {
-      input: {} as I,
-      output: {} as O | undefined,
-    },
-  };
+/**
+ * Deep equality used by `array().unique()`. Plain objects compare their own
+ * enumerable keys regardless of order, arrays, Maps and Sets compare entries in
+ * order, Dates compare their time, and values with different prototypes are
+ * never equal. Cycles are treated as equal once the same pair is revisited.
+ */
+function structurallyEqual(a: unknown, b: unknown, seen = new Map<object, object>()): boolean {
+  if (a === b || (a !== a && b !== b)) {
+    return true;
+  }
+  if (typeof a !== "object" || typeof b !== "object" || a === null || b === null) {
+    return false;
+  }
+  if (Object.getPrototypeOf(a) !== Object.getPrototypeOf(b)) {
+    return false;
+  }
+  if (seen.get(a) === b) {
+    return true;
+  }
+  seen.set(a, b);
+  if (a instanceof Date) {
+    return structurallyEqual(a.getTime(), (b as Date).getTime());
+  }
+  if (a instanceof Map || a instanceof Set || Array.isArray(a)) {
+    const left = [...(a as Iterable<unknown>)];
+    const right = [...(b as Iterable<unknown>)];
+    return (
+      left.length === right.length &&
+      left.every((item, index) => structurallyEqual(item, right[index], seen))
+    );
+  }
+  const keys = Object.keys(a);
+  return (
+    keys.length === Object.keys(b).length &&
+    keys.every(
+      (key) =>
+        Object.hasOwn(b, key) &&
+        structurallyEqual((a as Record<string, unknown>)[key], (b as any)[key], seen),
+    )
+  );
 }
 
-export class NullSchemaType extends BaseSchema<unknown, null> {
-  readonly type = "null";
-  constructor() {
-    super();
-    this.jsonSchema = { type: "null" };
+function validatePrimitive(schema: SchemaPrimitive, value: unknown): boolean {
+  if (typeof value === "string" && schema === "string") {
+    return true;
+  }
+  if (typeof value === "number" && schema === "number" && !Number.isNaN(value)) {
+    return true;
+  }
+  if (typeof value === "boolean" && schema === "boolean") {
+    return true;
   }
+  return false;
+}
 
-  readonly "~standard": CombinedStandardProps<unknown, null> = {
-    version: 1,
-    vendor: "h-schema",
-    jsonSchema: {
-      input: () => this.jsonSchema,
-      output: () => this.jsonSchema,
-    },
-    validate: (value: unknown) => {
-      if (value !== null) {
-        return {
-          issues: [
-            {
-              message: `Expected null, received ${value === undefined ? "undefined" : typeof value}`,
-            },
-          ],
-        };
-      }
-      return { value: null };
-    },
-    types: {
-      input: {} as unknown,
-      output: {} as unknown as null,
-    },
-  };
+const formats = new Map<string, RegExp>();
+
+const NUMERIC_IDENTIFIER = /^(0|[1-9][0-9]*)$/;
+const ALPHANUMERIC_IDENTIFIER = /^[0-9A-Za-z-]+$/;
+
+const EMOJI_FLAG = /\p{Regional_Indicator}{2}/u.source;
+const EMOJI_KEYCAP = /[#*0-9]\uFE0F?\u20E3/u.source;
+const EMOJI_ELEMENT =
+  /\p{Extended_Pictographic}\uFE0F?\p{Emoji_Modifier}?(?:[\u{E0020}-\u{E007E}]+\u{E007F})?/u.source;
+const EMOJI_ZWJ_SEQUENCE = `${EMOJI_ELEMENT}(?:\\u200D${EMOJI_ELEMENT})*`;
+
+/** A single emoji grapheme: ZWJ sequences, skin tones, flags and keycaps */
+const EMOJI_SEQUENCE = `(?:${EMOJI_FLAG}|${EMOJI_KEYCAP}|${EMOJI_ZWJ_SEQUENCE})`;
+const SINGLE_EMOJI = new RegExp(`^${EMOJI_SEQUENCE}$`, "u");
+const MULTIPLE_EMOJI = new RegExp(`^${EMOJI_SEQUENCE}+$`, "u");
+const ANY_EMOJI = new RegExp(EMOJI_SEQUENCE, "u");
+const CONTROL_CHAR = /\p{Cc}/u;
+const UTF8 = new TextEncoder();
+
+const MIME_TOKEN = "[a-z0-9!#$&^_.+-]+";
+/** RFC 2397 `data:[<mediatype>][;base64],<data>`, where the media type defaults to `text/plain` */
+const DATA_URI = new RegExp(
+  `^data:(${MIME_TOKEN}/${MIME_TOKEN})?((?:;${MIME_TOKEN}=[^;,]*)*)(;base64)?,(.*)$`,
+  "is",
+);
+const RESTRICTED_NAME = "[a-z0-9][a-z0-9!#$&^_.+-]{0,126}";
+/** RFC 6838 `type/subtype` followed by optional `; name=value` parameters */
+const MIME_TYPE = new RegExp(
+  `^${RESTRICTED_NAME}/${RESTRICTED_NAME}(?:\\s*;\\s*${MIME_TOKEN}=(?:${MIME_TOKEN}|"[^"]*"))*$`,
+  "i",
+);
+const BASE64 = /^([A-Za-z0-9+/]{4})*([A-Za-z0-9+/]{2}==|[A-Za-z0-9+/]{3}=)?$/;
+
+/** Verify an ISBN-10 (mod 11, `X` as 10) or ISBN-13 (mod 10) check digit, ignoring separators */
+function isIsbn(value: string, version?: IsbnVersion): boolean {
+  const digits = value.replace(/[-\s]/g, "").toUpperCase();
+  if (version !== 13 && /^\d{9}[\dX]$/.test(digits)) {
+    let sum = 0;
+    for (let i = 0; i < 10; i++) {
+      sum += (10 - i) * (digits[i] === "X" ? 10 : Number(digits[i]));
+    }
+    return sum % 11 === 0;
+  }
+  if (version !== 10 && /^\d{13}$/.test(digits)) {
+    let sum = 0;
+    for (let i = 0; i < 13; i++) {
+      sum += (i % 2 === 0 ? 1 : 3) * Number(digits[i]);
+    }
+    return sum % 10 === 0;
+  }
+  return false;
 }
 
-export class UnionSchema<I, O> extends BaseSchema<I, O> {
-  private readonly schemas: Schema<I, any>[];
-  constructor(...schemas: Schema<I, any>[]) {
-    super();
-    this.schemas = schemas;
-    this.jsonSchema = { anyOf: schemas.map((s) => s.jsonSchema) };
+/** Parse a SemVer 2.0 version: `MAJOR.MINOR.PATCH[-pre.release][+build.metadata]` */
+function isSemver(value: string): boolean {
+  const plus = value.indexOf("+");
+  const version = plus === -1 ? value : value.slice(0, plus);
+  if (plus !== -1) {
+    const build = value.slice(plus + 1).split(".");
+    if (!build.every((id) => ALPHANUMERIC_IDENTIFIER.test(id))) {
+      return false;
+    }
   }
 
-  readonly "~standard": CombinedStandardProps<I, O> = {
-    version: 1,
-    vendor: "h-schema",
-    jsonSchema: {
-      input: () => this.jsonSchema,
-      output: () => this.jsonSchema,
-    },
-    validate: (value: unknown) => {
-      const issuesAccum: StandardSchemaV1.Issue[] = [];
-      for (const schema of this.schemas) {
-        const result = schema["~standard"].validate(value) as StandardSchemaV1.Result<any>;
-        if (!("issues" in result)) {
-          return { value: result.value };
-        }
-        issuesAccum.push(...result.issues!);
-      }
-      return { issues: issuesAccum };
-    },
-    types: {
-      input: {} as I,
-      output: {} as O,
-    },
-  };
+  const dash = version.indexOf("-");
+  const core = dash === -1 ? version : version.slice(0, dash);
+  if (dash !== -1) {
+    const preRelease = version.slice(dash + 1).split(".");
+    const valid = preRelease.every(
+      (id) =>
+        ALPHANUMERIC_IDENTIFIER.test(id) && (!/^[0-9]+$/.test(id) || NUMERIC_IDENTIFIER.test(id)),
+    );
+    if (!valid) {
+      return false;
+    }
+  }
+
+  const parts = core.split(".");
+  return parts.length === 3 && parts.every((part) => NUMERIC_IDENTIFIER.test(part));
 }
 
-export class ArraySchema<I, O extends any[]> extends BaseSchema<I, O> {
-  private readonly innerSchema: Schema<I, O[number]>;
+/**
+ * Register a named string format for use with `h.string().format(name)`
+ * @param {string} name - Format name, also emitted as the JSON Schema `format`
+ * @param {RegExp | string} regex - Pattern the string must match
+ */
+export function registerFormat(name: string, regex: RegExp | string): void {
+  const pattern = typeof regex === "string" ? new RegExp(regex) : regex;
+  formats.set(name, new RegExp(pattern.source, pattern.flags.replace(/[gy]/g, "")));
+}
 
-  constructor(schema: Schema<I, O[number]>) {
-    super();
-    this.innerSchema = schema;
-    this.jsonSchema = { type: "array", items: schema.jsonSchema };
+const CURRENCIES: ReadonlySet<string> = new Set(CURRENCY_CODES);
+const COUNTRIES_ALPHA2: ReadonlySet<string> = new Set(COUNTRY_CODES_ALPHA2);
+const COUNTRIES_ALPHA3: ReadonlySet<string> = new Set(COUNTRY_CODES_ALPHA3);
+
+/** IANA time zone areas, used to check zone names when `Intl` is unavailable */
+const TIMEZONE_AREAS = [
+  "Africa",
+  "America",
+  "Antarctica",
+  "Arctic",
+  "Asia",
+  "Atlantic",
+  "Australia",
+  "Europe",
+  "Indian",
+  "Pacific",
+  "Etc",
+];
+
+/** Check a dotted-decimal IPv4 address without leading zeros */
+function isIPv4(value: string): boolean {
+  const parts = value.split(".");
+  return (
+    parts.length === 4 &&
+    parts.every((part) => /^(0|[1-9][0-9]{0,2})$/.test(part) && Number(part) <= 255)
+  );
+}
+
+/** Check an IPv6 address, allowing `::` compression and a trailing IPv4 part */
+function isIPv6(value: string): boolean {
+  const halves = value.split("::");
+  if (halves.length > 2) {
+    return false;
+  }
+  const head = halves[0] ? halves[0].split(":") : [];
+  const tail = halves[1] ? halves[1].split(":") : [];
+  const groups = [...head, ...tail];
+  const final = halves.length === 2 ? tail : head;
+  let slots = groups.length;
+  if (final[final.length - 1]?.includes(".")) {
+    if (!isIPv4(groups.pop()!)) {
+      return false;
+    }
+    slots = groups.length + 2;
+  }
+  if (!groups.every((group) => /^[0-9a-f]{1,4}$/i.test(group))) {
+    return false;
   }
+  return halves.length === 2 ? slots < 8 : slots === 8;
+}
 
-  readonly "~standard": CombinedStandardProps<I, O> = {
-    version: 1,
-    vendor: "h-schema",
-    jsonSchema: {
-      input: () => this.jsonSchema,
-      output: () => this.jsonSchema,
-    },
-    validate: (value: unknown) => {
-      if (!Array.isArray(value)) {
-        return {
-          issues: [{ message: `Expected array, received ${typeof value}` }],
-        };
-      }
+/** Check an IANA zone name by letting `Intl.DateTimeFormat` reject it with a `RangeError` */
+function isTimezone(value: string): boolean {
+  if (typeof Intl === "undefined" || typeof Intl.DateTimeFormat !== "function") {
+    const [area, ...location] = value.split("/");
+    return value === "UTC" || (TIMEZONE_AREAS.includes(area!) && location.length > 0);
+  }
+  try {
+    new Intl.DateTimeFormat(undefined, { timeZone: value });
+    return true;
+  } catch (error) {
+    if (error instanceof RangeError) {
+      return false;
+    }
+    throw error;
+  }
+}
 
-      const results = value.map((item, index) => {
-        const result = this.innerSchema["~standard"].validate(item) as StandardSchemaV1.Result<
-          O[number]
-        >;
-        if ("issues" in result) {
-          return {
-            index,
-            issues: result.issues?.map((issue) => ({
-              ...issue,
-              path: issue.path ? [index, ...issue.path] : [index],
-            })),
-          };
+/** Fallback for countries without a known pattern: 3 to 10 letters, digits, spaces or dashes */
+const ANY_POSTAL_CODE = /^[A-Z\d][A-Z\d -]{1,8}[A-Z\d]$/i;
+
+/** ISO 3166-1 code set accepted by `countryCode()` */
+export type CountryCodeVariant = "alpha2" | "alpha3";
+
+/** Separator accepted by `macAddress()`; `"."` selects the dotted `001A.2B3C.4D5E` form */
+export type MacSeparator = ":" | "-" | ".";
+
+/** ISBN edition accepted by `isbn()`; both are accepted when omitted */
+export type IsbnVersion = 10 | 13;
+
+/** Requirements checked by `string().password()`; everything is required by default */
+export interface PasswordPolicy {
+  /** Minimum number of characters, 8 by default */
+  minLength?: number;
+  upper?: boolean;
+  lower?: boolean;
+  digit?: boolean;
+  symbol?: boolean;
+}
+
+/** Unicode normalization form applied by `string().normalize()` */
+export type NormalizationForm = "NFC" | "NFD" | "NFKC" | "NFKD";
+
+type StringCheck =
+  | "nonempty"
+  | "minLength"
+  | "maxLength"
+  | "minBytes"
+  | "maxBytes"
+  | "date"
+  | "uuid"
+  | "regex"
+  | "email"
+  | "phone"
+  | "domain"
+  | "format"
+  | "hexColor"
+  | "semver"
+  | "emoji"
+  | "slug"
+  | "macAddress"
+  | "hostname"
+  | "currencyCode"
+  | "countryCode"
+  | "postalCode"
+  | "timezone"
+  | "ipOrHostname"
+  | "hex"
+  | "base32"
+  | "objectId"
+  | "isbn"
+  | "dataUri"
+  | "mimeType"
+  | "fileExtension"
+  | "password"
+  | "nanoid"
+  | "noEmoji"
+  | "noControlChars"
+  | "includesAny"
+  | "excludes"
+  | "datetime"
+  | "datetimeLocal";
+
+const DATETIME =
+  /^(\d{4})-(\d{2})-(\d{2})T(\d{2}):(\d{2})(?::(\d{2})(?:\.(\d+))?)?(Z|[+-]\d{2}:\d{2})?$/i;
+
+interface ParsedDatetime {
+  /** Milliseconds since the epoch in UTC, excluding the fractional seconds */
+  time: number;
+  fraction?: string;
+  offset?: string;
+}
+
+function daysInMonth(year: number, month: number): number {
+  const leap = (year % 4 === 0 && year % 100 !== 0) || year % 400 === 0;
+  return [31, leap ? 29 : 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31][month - 1]!;
+}
+
+function parseDatetime(value: string): ParsedDatetime | undefined {
+  const match = DATETIME.exec(value);
+  if (!match) {
+    return undefined;
+  }
+  const [year, month, day, hour, minute, second] = match
+    .slice(1, 7)
+    .map((part) => Number(part ?? 0)) as [number, number, number, number, number, number];
+  const fraction = match[7];
+  const offset = match[8]?.toUpperCase();
+  if (
+    month < 1 ||
+    month > 12 ||
+    day < 1 ||
+    day > daysInMonth(year, month) ||
+    hour > 23 ||
+    minute > 59 ||
+    second > 59
+  ) {
+    return undefined;
+  }
+  let offsetMinutes = 0;
+  if (offset && offset !== "Z") {
+    const offsetHours = Number(offset.slice(1, 3));
+    const offsetRest = Number(offset.slice(4));
+    if (offsetHours > 23 || offsetRest > 59) {
+      return undefined;
+    }
+    offsetMinutes = (offsetHours * 60 + offsetRest) * (offset.startsWith("-") ? -1 : 1);
+  }
+  const date = new Date(0);
+  date.setUTCFullYear(year, month - 1, day);
+  date.setUTCHours(hour, minute - offsetMinutes, second, 0);
+  return { time: date.getTime(), fraction, offset };
+}
+
+/**
+ * Re-emits a parsed date-time as `YYYY-MM-DDTHH:mm:ss[.fraction]Z`, or `undefined` when the
+ * offset moves it out of the four-digit years, e.g. `0000-01-01T00:30:00+01:00`
+ */
+function toUtcDatetime({ time, fraction }: ParsedDatetime): string | undefined {
+  const date = new Date(time);
+  const year = date.getUTCFullYear();
+  if (year < 0 || year > 9999) {
+    return undefined;
+  }
+  const seconds = date.toISOString().slice(0, 19);
+  return fraction ? `${seconds}.${fraction}Z` : `${seconds}Z`;
+}
+
+/** URL-safe alphabet used by nanoid's default generator */
+const NANOID_ALPHABET = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789_-";
+
+export class StringSchemaType extends BaseSchema<unknown, string> {
+  readonly type: SchemaPrimitive = "string";
+  private _validateDate = false;
+  private _datetime?: { local: boolean; normalize: boolean };
+  private _validateUUID = false;
+  private _regex?: RegExp;
+  private _validateEmail = false;
+  private _validatePhone = false;
+  private _validateDomain = false;
+  private _requireHttpOrHttps = false;
+  private _validateHexColor = false;
+  private _allowAlpha = false;
+  private _validateSemver = false;
+  private _validateEmoji = false;
+  private _allowMultipleEmoji = false;
+  private _validateSlug = false;
+  private _macSeparators?: MacSeparator[];
+  private _validateHostname = false;
+  private _currencyCodes?: ReadonlySet<string>;
+  private _countryCodes?: ReadonlySet<string>;
+  private _postalCode?: RegExp;
+  private _validateTimezone = false;
+  private _validateIpOrHostname = false;
+  private _validateHex = false;
+  private _allowHexPrefix = false;
+  private _validateBase32 = false;
+  private _validateObjectId = false;
+  private _isbn?: { version?: IsbnVersion };
+  private _dataUri?: { mimePrefixes?: string[] };
+  private _validateMimeType = false;
+  private _fileExtensions?: string[];
+  private _password?: Required<PasswordPolicy>;
+  private _nanoid?: { size: number; alphabet: ReadonlySet<string> };
+  private _format?: { name: string; regex: RegExp };
+  private _minLength?: number;
+  private _maxLength?: number;
+  private _minBytes?: number;
+  private _maxBytes?: number;
+  private _trim = false;
+  private _normalize?: NormalizationForm;
+  private _nonempty = false;
+  private _noEmoji = false;
+  private _noControlChars = false;
+  private _includesAny?: string[];
+  private _excludes?: string[];
+  private _messages: Partial<Record<StringCheck, string>> = {};
+
+  constructor() {
+    super();
+    this.jsonSchema = { type: "string" };
+  }
+
+  primitive(): SchemaPrimitive {
+    return this.type;
+  }
+
+  getMinLength(): number | undefined {
+    return this._minLength;
+  }
+
+  getMaxLength(): number | undefined {
+    return this._maxLength;
+  }
+
+  trim(): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._trim = true;
+    return schema;
+  }
+
+  normalize(form: NormalizationForm = "NFC"): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._normalize = form;
+    return schema;
+  }
+
+  nonempty(message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._nonempty = true;
+    schema._messages = { ...this._messages, nonempty: message };
+    schema.jsonSchema = { ...this.jsonSchema, minLength: Math.max(this._minLength ?? 0, 1) };
+    return schema;
+  }
+
+  minLength(n: number, message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._minLength = n;
+    schema._messages = { ...this._messages, minLength: message };
+    schema.jsonSchema = {
+      ...this.jsonSchema,
+      minLength: n,
+    };
+    return schema;
+  }
+
+  maxLength(n: number, message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._maxLength = n;
+    schema._messages = { ...this._messages, maxLength: message };
+    schema.jsonSchema = {
+      ...this.jsonSchema,
+      maxLength: n,
+    };
+    return schema;
+  }
+
+  minBytes(n: number, message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._minBytes = n;
+    schema._messages = { ...this._messages, minBytes: message };
+    schema.jsonSchema = { ...this.jsonSchema, "x-minBytes": n };
+    return schema;
+  }
+
+  maxBytes(n: number, message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._maxBytes = n;
+    schema._messages = { ...this._messages, maxBytes: message };
+    schema.jsonSchema = { ...this.jsonSchema, "x-maxBytes": n };
+    return schema;
+  }
+
+  date(message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._validateDate = true;
+    schema._messages = { ...this._messages, date: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "date" };
+    return schema;
+  }
+
+  datetime(normalize = false, message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._datetime = { local: false, normalize };
+    schema._messages = { ...this._messages, datetime: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "date-time" };
+    return schema;
+  }
+
+  datetimeLocal(message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._datetime = { local: true, normalize: false };
+    schema._messages = { ...this._messages, datetimeLocal: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "datetime-local" };
+    return schema;
+  }
+
+  uuid(message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._validateUUID = true;
+    schema._messages = { ...this._messages, uuid: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "uuid" };
+    return schema;
+  }
+
+  regex(regex: RegExp, message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._regex = new RegExp(regex.source, regex.flags.replace(/[gy]/g, ""));
+    schema._messages = { ...this._messages, regex: message };
+    schema.jsonSchema = { ...this.jsonSchema, pattern: regex.source };
+    return schema;
+  }
+
+  email(message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._validateEmail = true;
+    schema._messages = { ...this._messages, email: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "email" };
+    return schema;
+  }
+
+  phone(message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._validatePhone = true;
+    schema._messages = { ...this._messages, phone: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "phone" };
+    return schema;
+  }
+
+  domain(requireHttpOrHttps = true, message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._validateDomain = true;
+    schema._messages = { ...this._messages, domain: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "domain" };
+    schema._requireHttpOrHttps = requireHttpOrHttps;
+    return schema;
+  }
+
+  hexColor(allowAlpha = false, message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._validateHexColor = true;
+    schema._allowAlpha = allowAlpha;
+    schema._messages = { ...this._messages, hexColor: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "color" };
+    return schema;
+  }
+
+  semver(message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._validateSemver = true;
+    schema._messages = { ...this._messages, semver: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "semver" };
+    return schema;
+  }
+
+  emoji(allowMultiple = false, message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._validateEmoji = true;
+    schema._allowMultipleEmoji = allowMultiple;
+    schema._messages = { ...this._messages, emoji: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "emoji" };
+    return schema;
+  }
+
+  slug(message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._validateSlug = true;
+    schema._messages = { ...this._messages, slug: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "slug" };
+    return schema;
+  }
+
+  hex(allowPrefix = false, message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._validateHex = true;
+    schema._allowHexPrefix = allowPrefix;
+    schema._messages = { ...this._messages, hex: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "hex" };
+    return schema;
+  }
+
+  base32(message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._validateBase32 = true;
+    schema._messages = { ...this._messages, base32: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "base32" };
+    return schema;
+  }
+
+  objectId(message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._validateObjectId = true;
+    schema._messages = { ...this._messages, objectId: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "objectid" };
+    return schema;
+  }
+
+  isbn(version?: IsbnVersion, message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._isbn = { version };
+    schema._messages = { ...this._messages, isbn: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "isbn" };
+    return schema;
+  }
+
+  dataUri(mimePrefixes?: string[], message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._dataUri = { mimePrefixes: mimePrefixes?.map((prefix) => prefix.toLowerCase()) };
+    schema._messages = { ...this._messages, dataUri: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "data-uri" };
+    return schema;
+  }
+
+  password(policy: PasswordPolicy = {}, message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._password = {
+      minLength: 8,
+      upper: true,
+      lower: true,
+      digit: true,
+      symbol: true,
+      ...policy,
+    };
+    schema._messages = { ...this._messages, password: message };
+    schema.jsonSchema = {
+      ...this.jsonSchema,
+      format: "password",
+      minLength: Math.max(this.jsonSchema.minLength ?? 0, schema._password.minLength),
+    };
+    return schema;
+  }
+
+  mimeType(message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._validateMimeType = true;
+    schema._messages = { ...this._messages, mimeType: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "mime-type" };
+    return schema;
+  }
+
+  fileExtension(allowed: string[], message?: string): StringSchemaType {
+    const extensions = allowed.map((extension) => extension.replace(/^\./, "").toLowerCase());
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._fileExtensions = extensions;
+    schema._messages = { ...this._messages, fileExtension: message };
+    schema.jsonSchema = { ...this.jsonSchema, "x-file-extensions": extensions };
+    return schema;
+  }
+
+  nanoid(size = 21, alphabet = NANOID_ALPHABET, message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._nanoid = { size, alphabet: new Set(alphabet) };
+    schema._messages = { ...this._messages, nanoid: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "nanoid" };
+    return schema;
+  }
+
+  noEmoji(message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._noEmoji = true;
+    schema._messages = { ...this._messages, noEmoji: message };
+    return schema;
+  }
+
+  noControlChars(message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._noControlChars = true;
+    schema._messages = { ...this._messages, noControlChars: message };
+    return schema;
+  }
+
+  includesAny(substrings: string[], message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._includesAny = [...substrings];
+    schema._messages = { ...this._messages, includesAny: message };
+    return schema;
+  }
+
+  excludes(substrings: string[], message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._excludes = [...substrings];
+    schema._messages = { ...this._messages, excludes: message };
+    return schema;
+  }
+
+  macAddress(separators: MacSeparator[] = [":", "-"], message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._macSeparators = separators;
+    schema._messages = { ...this._messages, macAddress: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "mac" };
+    return schema;
+  }
+
+  hostname(message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._validateHostname = true;
+    schema._messages = { ...this._messages, hostname: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "hostname" };
+    return schema;
+  }
+
+  currencyCode(message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._currencyCodes = CURRENCIES;
+    schema._messages = { ...this._messages, currencyCode: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "currency", enum: [...CURRENCY_CODES] };
+    return schema;
+  }
+
+  countryCode(variant: CountryCodeVariant = "alpha2", message?: string): StringSchemaType {
+    const codes = variant === "alpha3" ? COUNTRY_CODES_ALPHA3 : COUNTRY_CODES_ALPHA2;
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._countryCodes = variant === "alpha3" ? COUNTRIES_ALPHA3 : COUNTRIES_ALPHA2;
+    schema._messages = { ...this._messages, countryCode: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "country", enum: [...codes] };
+    return schema;
+  }
+
+  postalCode(country: string, message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._postalCode = POSTAL_CODES[country.toUpperCase()] ?? ANY_POSTAL_CODE;
+    schema._messages = { ...this._messages, postalCode: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "postal-code", "x-country": country };
+    return schema;
+  }
+
+  timezone(message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._validateTimezone = true;
+    schema._messages = { ...this._messages, timezone: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: "timezone" };
+    return schema;
+  }
+
+  ipOrHostname(message?: string): StringSchemaType {
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._validateIpOrHostname = true;
+    schema._messages = { ...this._messages, ipOrHostname: message };
+    schema.jsonSchema = {
+      ...this.jsonSchema,
+      format: "hostname",
+      anyOf: [{ format: "ipv4" }, { format: "ipv6" }, { format: "hostname" }],
+    };
+    return schema;
+  }
+
+  format(name: string, message?: string): StringSchemaType {
+    const regex = formats.get(name);
+    if (!regex) {
+      throw new Error(`Unknown string format: ${name}`);
+    }
+    const schema = new StringSchemaType();
+    Object.assign(schema, this);
+    schema._format = { name, regex };
+    schema._messages = { ...this._messages, format: message };
+    schema.jsonSchema = { ...this.jsonSchema, format: name };
+    return schema;
+  }
+
+  /** Normalize coerced input into the canonical form of its format before checking it */
+  private _coerceFormat(value: string): string {
+    if (this._validateEmail) {
+      return value.trim().toLowerCase();
+    }
+    if (this._currencyCodes || this._countryCodes) {
+      return value.trim().toUpperCase();
+    }
+    return value;
+  }
+
+  /** One issue per unmet password requirement, so that forms can render a checklist */
+  private _passwordIssues(value: string, options?: StandardSchemaV1.Options): ValidationIssue[] {
+    const { minLength, upper, lower, digit, symbol } = this._password!;
+    const results: ValidationResult<string>[] = [];
+    if (value.length < minLength) {
+      const message = `Password must be at least ${minLength} characters`;
+      results.push(this._issue("password", message, "too_small", { min: minLength }));
+    }
+    const requirements: [boolean, string][] = [
+      [upper && !/\p{Lu}/u.test(value), "an uppercase letter"],
+      [lower && !/\p{Ll}/u.test(value), "a lowercase letter"],
+      [digit && !/\p{Nd}/u.test(value), "a digit"],
+      [symbol && !/[^\p{L}\p{N}\s]/u.test(value), "a symbol"],
+    ];
+    for (const [unmet, requirement] of requirements) {
+      if (unmet) {
+        const message = `Password must contain ${requirement}`;
+        results.push(this._issue("password", message, "invalid_string"));
+      }
+    }
+    const issues = results.flatMap((result) => result.issues ?? []);
+    return isAbortEarly(options) || this._messages.password !== undefined
+      ? issues.slice(0, 1)
+      : issues;
+  }
+
+  protected _validate(
+    value: unknown,
+    options?: StandardSchemaV1.Options,
+  ): ValidationResult<string> {
+    if (this._coerce && typeof value !== "string") {
+      value = String(value);
+    }
+
+    if (typeof value !== "string") {
+      return { issues: [typeMismatch("string", value)] };
+    }
+
+    if (this._coerce) {
+      value = this._coerceFormat(value);
+    }
+
+    if (this._trim) {
+      value = value.trim();
+    }
+
+    if (this._normalize) {
+      value = value.normalize(this._normalize);
+    }
+
+    if (this._nonempty && value.length === 0) {
+      return this._issue("nonempty", "String must not be empty", "empty_string");
+    }
+
+    if (this._minLength !== undefined && value.length < this._minLength) {
+      return this._issue("minLength", `String shorter than ${this._minLength}`, "too_small", {
+        min: this._minLength,
+      });
+    }
+
+    if (this._maxLength !== undefined && value.length > this._maxLength) {
+      return this._issue("maxLength", `String longer than ${this._maxLength}`, "too_big", {
+        max: this._maxLength,
+      });
+    }
+
+    const passwordIssues = this._password ? this._passwordIssues(value, options) : [];
+    if (passwordIssues.length > 0) {
+      return { issues: passwordIssues };
+    }
+
+    if (this._minBytes !== undefined || this._maxBytes !== undefined) {
+      const bytes = UTF8.encode(value).length;
+      if (this._minBytes !== undefined && bytes < this._minBytes) {
+        const message = `String shorter than ${this._minBytes} bytes`;
+        return this._issue("minBytes", message, "too_small", { min: this._minBytes });
+      }
+      if (this._maxBytes !== undefined && bytes > this._maxBytes) {
+        const message = `String longer than ${this._maxBytes} bytes`;
+        return this._issue("maxBytes", message, "too_big", { max: this._maxBytes });
+      }
+    }
+
+    const emojiIndex = this._noEmoji ? value.search(ANY_EMOJI) : -1;
+    if (emojiIndex !== -1) {
+      const message = `String contains an emoji at index ${emojiIndex}`;
+      return this._issue("noEmoji", message, "invalid_string");
+    }
+
+    const controlIndex = this._noControlChars ? value.search(CONTROL_CHAR) : -1;
+    if (controlIndex !== -1) {
+      const message = `String contains a control character at index ${controlIndex}`;
+      return this._issue("noControlChars", message, "invalid_string");
+    }
+
+    if (this._includesAny && !this._includesAny.some((substring) => value.includes(substring))) {
+      const expected = this._includesAny.map((substring) => JSON.stringify(substring)).join(" | ");
+      return this._issue("includesAny", `String must include one of ${expected}`, "invalid_string");
+    }
+
+    const forbidden = this._excludes?.find((substring) => value.includes(substring));
+    if (forbidden !== undefined) {
+      const message = `String must not include ${JSON.stringify(forbidden)}`;
+      return this._issue("excludes", message, "invalid_string");
+    }
+
+    if (this._validateUUID && !this._isValidUUID(value)) {
+      return this._issue("uuid", "Invalid UUID format", "invalid_string");
+    }
+
+    if (this._regex && !this._regex.test(value)) {
+      return this._issue("regex", "Invalid regex format", "invalid_string");
+    }
+
+    if (this._validateEmail && !this._isValidEmail(value)) {
+      return this._issue("email", "Invalid email format", "invalid_string");
+    }
+
+    if (this._validatePhone && !this._isValidPhone(value)) {
+      return this._issue("phone", "Invalid phone number format", "invalid_string");
+    }
+
+    if (this._validateDomain && !this._isValidDomain(value)) {
+      return this._issue("domain", "Invalid domain format", "invalid_string");
+    }
+
+    if (this._validateHexColor && !this._isValidHexColor(value)) {
+      return this._issue("hexColor", "Invalid hex color format", "invalid_string");
+    }
+
+    if (this._validateSemver && !isSemver(value)) {
+      return this._issue("semver", "Invalid semver format", "invalid_string");
+    }
+
+    if (this._validateEmoji && !this._isValidEmoji(value)) {
+      return this._issue("emoji", "Invalid emoji format", "invalid_string");
+    }
+
+    if (this._validateSlug && !this._isValidSlug(value)) {
+      return this._issue("slug", "Invalid slug format", "invalid_string");
+    }
+
+    if (this._validateHex && !this._isValidHex(value)) {
+      return this._issue("hex", "Invalid hex format", "invalid_string");
+    }
+
+    if (this._validateBase32 && !this._isValidBase32(value)) {
+      return this._issue("base32", "Invalid base32 format", "invalid_string");
+    }
+
+    if (this._validateObjectId && !this._isValidObjectId(value)) {
+      return this._issue("objectId", "Invalid ObjectId", "invalid_string");
+    }
+
+    if (this._isbn && !isIsbn(value, this._isbn.version)) {
+      return this._issue("isbn", "Invalid ISBN", "invalid_string");
+    }
+
+    if (this._dataUri && !this._isValidDataUri(value)) {
+      return this._issue("dataUri", "Invalid data URI", "invalid_string");
+    }
+
+    if (this._validateMimeType && !MIME_TYPE.test(value)) {
+      return this._issue("mimeType", "Invalid MIME type", "invalid_string");
+    }
+
+    if (this._fileExtensions && !this._isAllowedFileExtension(value)) {
+      const expected = this._fileExtensions.map((extension) => `.${extension}`).join(" | ");
+      const message = `File extension must be one of ${expected}`;
+      return this._issue("fileExtension", message, "invalid_string");
+    }
+
+    if (this._nanoid && !this._isValidNanoid(value)) {
+      return this._issue("nanoid", "Invalid nanoid format", "invalid_string");
+    }
+
+    if (this._macSeparators && !this._isValidMacAddress(value)) {
+      return this._issue("macAddress", "Invalid MAC address format", "invalid_string");
+    }
+
+    if (this._validateHostname && !this._isValidHostname(value)) {
+      return this._issue("hostname", "Invalid hostname format", "invalid_string");
+    }
+
+    if (this._currencyCodes && !this._currencyCodes.has(value)) {
+      return this._issue("currencyCode", "Invalid currency code", "invalid_string");
+    }
+
+    if (this._countryCodes && !this._countryCodes.has(value)) {
+      return this._issue("countryCode", "Invalid country code", "invalid_string");
+    }
+
+    if (this._postalCode && !this._postalCode.test(value)) {
+      return this._issue("postalCode", "Invalid postal code", "invalid_string");
+    }
+
+    if (this._validateTimezone && !isTimezone(value)) {
+      return this._issue("timezone", "Invalid timezone", "invalid_string");
+    }
+
+    if (
+      this._validateIpOrHostname &&
+      !isIPv4(value) &&
+      !isIPv6(value) &&
+      !this._isValidHostname(value)
+    ) {
+      return this._issue("ipOrHostname", "Expected an IP address or hostname", "invalid_string");
+    }
+
+    if (this._format && !this._format.regex.test(value)) {
+      return this._issue("format", `Invalid ${this._format.name}`, "invalid_string");
+    }
+
+    if (this._validateDate && !this._isValidDate(value)) {
+      return this._issue("date", "Invalid date format", "invalid_string");
+    }
+
+    if (this._datetime) {
+      const parsed = parseDatetime(value);
+      if (this._datetime.local && (!parsed || parsed.offset)) {
+        return this._issue("datetimeLocal", "Invalid local datetime format", "invalid_string");
+      }
+      if (!this._datetime.local && !parsed?.offset) {
+        return this._issue("datetime", "Invalid datetime format", "invalid_string");
+      }
+      if (this._datetime.normalize) {
+        const normalized = toUtcDatetime(parsed!);
+        if (normalized === undefined) {
+          return this._issue("datetime", "Datetime is out of range in UTC", "invalid_string");
+        }
+        return { value: normalized };
+      }
+    }
+
+    return { value };
+  }
+
+  private _issue(
+    check: StringCheck,
+    fallback: string,
+    code: IssueCode,
+    details?: IssueDetails,
+  ): ValidationResult<string> {
+    const message = this._messages[check];
+    if (message !== undefined) {
+      return { issues: [{ message, code }] };
+    }
+    return { issues: [builtinIssue(code, fallback, details)] };
+  }
+
+  private _isValidDate(value: string): boolean {
+    const date = new Date(value);
+    return !Number.isNaN(date.getTime());
+  }
+
+  private _isValidUUID(value: string): boolean {
+    const uuidRegex = /^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$/i;
+    return uuidRegex.test(value);
+  }
+
+  private _isValidEmail(value: string): boolean {
+    const emailRegex = /^[^\s@]+@[^\s@]+\.[^\s@]+$/;
+    return emailRegex.test(value);
+  }
+
+  private _isValidPhone(value: string): boolean {
+    const phoneRegex = /^\+?[0-9]{7,15}$/;
+    return phoneRegex.test(value);
+  }
+
+  private _isValidDomain(value: string): boolean {
+    let domainRegex = /^[a-z0-9]+([-.]{1}[a-z0-9]+)*\.[a-z]{2,6}$/;
+    if (this._requireHttpOrHttps) {
+      domainRegex = /^https?:\/\/[a-z0-9]+([-.]{1}[a-z0-9]+)*\.[a-z]{2,6}$/;
+    }
+    return domainRegex.test(value);
+  }
+
+  private _isValidHexColor(value: string): boolean {
+    const hexColorRegex = this._allowAlpha
+      ? /^#([0-9a-f]{3,4}|[0-9a-f]{6}|[0-9a-f]{8})$/i
+      : /^#([0-9a-f]{3}|[0-9a-f]{6})$/i;
+    return hexColorRegex.test(value);
+  }
+
+  private _isValidEmoji(value: string): boolean {
+    return (this._allowMultipleEmoji ? MULTIPLE_EMOJI : SINGLE_EMOJI).test(value);
+  }
+
+  private _isValidSlug(value: string): boolean {
+    const slugRegex = /^[a-z0-9]+(?:-[a-z0-9]+)*$/;
+    return slugRegex.test(value);
+  }
+
+  private _isValidHex(value: string): boolean {
+    const hexRegex = this._allowHexPrefix ? /^(0x)?([0-9a-f]{2})+$/i : /^([0-9a-f]{2})+$/i;
+    return hexRegex.test(value);
+  }
+
+  private _isValidBase32(value: string): boolean {
+    const base32Regex =
+      /^([A-Z2-7]{8})*([A-Z2-7]{8}|[A-Z2-7]{2}={6}|[A-Z2-7]{4}={4}|[A-Z2-7]{5}={3}|[A-Z2-7]{7}=)$/;
+    return base32Regex.test(value);
+  }
+
+  private _isValidDataUri(value: string): boolean {
+    const match = DATA_URI.exec(value);
+    if (!match) {
+      return false;
+    }
+    const [, mimeType = "text/plain", , base64, payload = ""] = match;
+    const { mimePrefixes } = this._dataUri!;
+    if (mimePrefixes && !mimePrefixes.some((prefix) => mimeType.toLowerCase().startsWith(prefix))) {
+      return false;
+    }
+    if (base64) {
+      return BASE64.test(payload);
+    }
+    try {
+      decodeURIComponent(payload);
+      return true;
+    } catch {
+      return false;
+    }
+  }
+
+  private _isAllowedFileExtension(value: string): boolean {
+    const name = value.slice(value.search(/[^/\\]*$/));
+    const dot = name.lastIndexOf(".");
+    return dot > 0 && this._fileExtensions!.includes(name.slice(dot + 1).toLowerCase());
+  }
+
+  private _isValidObjectId(value: string): boolean {
+    const objectIdRegex = /^[0-9a-f]{24}$/i;
+    return objectIdRegex.test(value);
+  }
+
+  private _isValidNanoid(value: string): boolean {
+    const { size, alphabet } = this._nanoid!;
+    const chars = [...value];
+    return chars.length === size && chars.every((char) => alphabet.has(char));
+  }
+
+  private _isValidHostname(value: string): boolean {
+    const hostname = value.endsWith(".") ? value.slice(0, -1) : value;
+    if (hostname.length === 0 || hostname.length > 253) {
+      return false;
+    }
+    const labelRegex = /^[a-z0-9]([a-z0-9-]{0,61}[a-z0-9])?$/i;
+    return hostname.split(".").every((label) => labelRegex.test(label));
+  }
+
+  private _isValidMacAddress(value: string): boolean {
+    return this._macSeparators!.some((separator) => {
+      const macRegex =
+        separator === "."
+          ? /^[0-9a-f]{4}(\.[0-9a-f]{4}){2}$/i
+          : new RegExp(`^[0-9a-f]{2}(${separator}[0-9a-f]{2}){5}$`, "i");
+      return macRegex.test(value);
+    });
+  }
+}
+
+/** Whether `value` lies on `base + k * step`, allowing for floating-point error */
+function isStepAligned(value: number, base: number, step: number): boolean {
+  const steps = (value - base) / step;
+  return Math.abs(steps - Math.round(steps)) < 1e-9;
+}
+
+/** Fixed-width integer type checked by `number().int8()` and friends */
+export type IntWidth = "int8" | "uint8" | "int16" | "uint16" | "int32" | "uint32";
+
+const INT_WIDTHS: Record<IntWidth, [min: number, max: number]> = {
+  int8: [-128, 127],
+  uint8: [0, 255],
+  int16: [-32768, 32767],
+  uint16: [0, 65535],
+  int32: [-2147483648, 2147483647],
+  uint32: [0, 4294967295],
+};
+
+export class NumberSchemaType extends BaseSchema<unknown, number> {
+  readonly type: SchemaPrimitive = "number";
+  private _min?: number;
+  private _max?: number;
+  private _integer = false;
+  private _step?: { base: number; step: number };
+  private _width?: IntWidth;
+  private _messages: {
+    min?: string;
+    max?: string;
+    int?: string;
+    step?: string;
+    width?: string;
+  } = {};
+
+  constructor() {
+    super();
+    this.jsonSchema = { type: "number" };
+  }
+
+  primitive(): SchemaPrimitive {
+    return this.type;
+  }
+
+  min(n: number, message?: string): NumberSchemaType {
+    const schema = new NumberSchemaType();
+    Object.assign(schema, this);
+    schema._min = n;
+    schema._messages = { ...this._messages, min: message };
+    schema.jsonSchema = {
+      ...this.jsonSchema,
+      minimum: n,
+    };
+    return schema;
+  }
+
+  max(n: number, message?: string): NumberSchemaType {
+    const schema = new NumberSchemaType();
+    Object.assign(schema, this);
+    schema._max = n;
+    schema._messages = { ...this._messages, max: message };
+    schema.jsonSchema = {
+      ...this.jsonSchema,
+      maximum: n,
+    };
+    return schema;
+  }
+
+  getType(): string {
+    return this.type;
+  }
+
+  int(message?: string): NumberSchemaType {
+    const schema = new NumberSchemaType();
+    Object.assign(schema, this);
+    schema._integer = true;
+    schema._messages = { ...this._messages, int: message };
+    schema.jsonSchema = { ...this.jsonSchema, type: "integer" };
+    return schema;
+  }
+
+  step(base: number, step: number, message?: string): NumberSchemaType {
+    if (!Number.isFinite(base)) {
+      throw new Error("step() requires a finite base");
+    }
+    if (!Number.isFinite(step) || step <= 0) {
+      throw new Error("step() requires a finite step greater than 0");
+    }
+    const schema = new NumberSchemaType();
+    Object.assign(schema, this);
+    schema._step = { base, step };
+    schema._messages = { ...this._messages, step: message };
+    schema.jsonSchema = { ...this.jsonSchema };
+    if (isStepAligned(base, 0, step)) {
+      schema.jsonSchema.multipleOf = step;
+    }
+    return schema;
+  }
+
+  safe(message?: string): NumberSchemaType {
+    return this.int(message)
+      .min(Number.MIN_SAFE_INTEGER, message)
+      .max(Number.MAX_SAFE_INTEGER, message);
+  }
+
+  port(unprivileged = false, message?: string): NumberSchemaType {
+    const schema = this.int(message)
+      .min(unprivileged ? 1024 : 0, message)
+      .max(65535, message);
+    schema.jsonSchema = { ...schema.jsonSchema, format: "port" };
+    return schema;
+  }
+
+  int8(message?: string): NumberSchemaType {
+    return this._fitIn("int8", message);
+  }
+
+  uint8(message?: string): NumberSchemaType {
+    return this._fitIn("uint8", message);
+  }
+
+  int16(message?: string): NumberSchemaType {
+    return this._fitIn("int16", message);
+  }
+
+  uint16(message?: string): NumberSchemaType {
+    return this._fitIn("uint16", message);
+  }
+
+  int32(message?: string): NumberSchemaType {
+    return this._fitIn("int32", message);
+  }
+
+  uint32(message?: string): NumberSchemaType {
+    return this._fitIn("uint32", message);
+  }
+
+  private _fitIn(width: IntWidth, message?: string): NumberSchemaType {
+    const [minimum, maximum] = INT_WIDTHS[width];
+    const schema = new NumberSchemaType();
+    Object.assign(schema, this);
+    schema._width = width;
+    schema._messages = { ...this._messages, width: message };
+    schema.jsonSchema = {
+      ...this.jsonSchema,
+      type: "integer",
+      minimum,
+      maximum,
+      "x-int-width": width,
+    };
+    return schema;
+  }
+
+  latitude(message?: string): NumberSchemaType {
+    const schema = this.min(-90, message).max(90, message);
+    schema.jsonSchema = { ...schema.jsonSchema, format: "latitude" };
+    return schema;
+  }
+
+  longitude(message?: string): NumberSchemaType {
+    const schema = this.min(-180, message).max(180, message);
+    schema.jsonSchema = { ...schema.jsonSchema, format: "longitude" };
+    return schema;
+  }
+
+  protected _validate(value: unknown): ValidationResult<number> {
+    if (this._coerce && typeof value === "string") {
+      const trimmed = value.trim();
+      if (trimmed === "") {
+        return {
+          issues: [
+            builtinIssue("invalid_type", "Expected number, received empty string", {
+              expected: "number",
+              received: "empty string",
+            }),
+          ],
+        };
+      }
+      const coerced = Number(trimmed);
+      if (Number.isFinite(coerced)) {
+        value = coerced;
+      }
+    } else if (this._coerce && typeof value !== "number") {
+      const coerced = Number(value);
+      if (!Number.isNaN(coerced)) {
+        value = coerced;
+      }
+    }
+    if (typeof value !== "number" || Number.isNaN(value)) {
+      return { issues: [typeMismatch("number", value)] };
+    }
+    if (this._width) {
+      const [min, max] = INT_WIDTHS[this._width];
+      if (!Number.isInteger(value) || value < min || value > max) {
+        const code: IssueCode = !Number.isInteger(value)
+          ? "invalid_type"
+          : value < min
+            ? "too_small"
+            : "too_big";
+        if (this._messages.width !== undefined) {
+          return { issues: [{ message: this._messages.width, code }] };
+        }
+        const message = `Number must fit in ${this._width} (${min}..=${max})`;
+        return { issues: [builtinIssue(code, message, { min, max })] };
+      }
+    }
+    if (this._integer && !Number.isInteger(value)) {
+      if (this._messages.int !== undefined) {
+        const message = this._messages.int;
+        return {
+          issues: [{ message, code: "invalid_type", expected: "integer", received: "number" }],
+        };
+      }
+      const message = `Expected integer, received ${value}`;
+      const details = { expected: "integer", received: "number" };
+      return { issues: [builtinIssue("invalid_type", message, details)] };
+    }
+    if (this._min !== undefined && value < this._min) {
+      if (this._messages.min !== undefined) {
+        return { issues: [{ message: this._messages.min, code: "too_small" }] };
+      }
+      const message = `Number less than ${this._min}`;
+      return { issues: [builtinIssue("too_small", message, { min: this._min })] };
+    }
+    if (this._max !== undefined && value > this._max) {
+      if (this._messages.max !== undefined) {
+        return { issues: [{ message: this._messages.max, code: "too_big" }] };
+      }
+      const message = `Number greater than ${this._max}`;
+      return { issues: [builtinIssue("too_big", message, { max: this._max })] };
+    }
+    if (this._step && !isStepAligned(value, this._step.base, this._step.step)) {
+      const { base, step } = this._step;
+      if (this._messages.step !== undefined) {
+        return { issues: [{ message: this._messages.step, code: "not_multiple_of" }] };
+      }
+      const message = `Number must be ${base} plus a multiple of ${step}`;
+      return { issues: [builtinIssue("not_multiple_of", message, { multipleOf: step })] };
+    }
+    return { value };
+  }
+}
+
+/** Strings `bigint().coerce()` converts without losing precision */
+const INTEGER_STRING = /^[+-]?\d+$/;
+
+function bigintBound(n: bigint | number, round: (n: number) => number, method: string): bigint {
+  if (typeof n === "bigint") {
+    return n;
+  }
+  if (!Number.isFinite(n)) {
+    throw new Error(`${method}() requires a finite number or a bigint`);
+  }
+  return BigInt(round(n));
+}
+
+/** JSON Schema bound for a bigint, left out when a JSON number cannot hold it exactly */
+function jsonBound(keyword: string, bound: bigint): Record<string, number> {
+  const value = Number(bound);
+  return Number.isSafeInteger(value) ? { [keyword]: value } : {};
+}
+
+export class BigIntSchemaType extends BaseSchema<unknown, bigint> {
+  readonly type = "bigint";
+  private _min?: bigint;
+  private _max?: bigint;
+
+  constructor() {
+    super();
+    this.jsonSchema = { type: "integer", format: "bigint" };
+  }
+
+  min(n: bigint | number): BigIntSchemaType {
+    const schema = new BigIntSchemaType();
+    Object.assign(schema, this);
+    schema._min = bigintBound(n, Math.ceil, "min");
+    schema.jsonSchema = { ...this.jsonSchema, ...jsonBound("minimum", schema._min) };
+    return schema;
+  }
+
+  max(n: bigint | number): BigIntSchemaType {
+    const schema = new BigIntSchemaType();
+    Object.assign(schema, this);
+    schema._max = bigintBound(n, Math.floor, "max");
+    schema.jsonSchema = { ...this.jsonSchema, ...jsonBound("maximum", schema._max) };
+    return schema;
+  }
+
+  getType(): string {
+    return this.type;
+  }
+
+  protected _validate(value: unknown): ValidationResult<bigint> {
+    if (this._coerce && typeof value === "string" && INTEGER_STRING.test(value.trim())) {
+      value = BigInt(value.trim());
+    } else if (this._coerce && typeof value === "number" && Number.isInteger(value)) {
+      value = BigInt(value);
+    }
+    if (typeof value !== "bigint") {
+      return { issues: [typeMismatch("bigint", value)] };
+    }
+    if (this._min !== undefined && value < this._min) {
+      const message = `BigInt less than ${this._min}`;
+      return { issues: [builtinIssue("too_small", message, { min: this._min })] };
+    }
+    if (this._max !== undefined && value > this._max) {
+      const message = `BigInt greater than ${this._max}`;
+      return { issues: [builtinIssue("too_big", message, { max: this._max })] };
+    }
+    return { value };
+  }
+}
+
+/** Inputs `boolean().coerce()` maps to `true` and `false`, comparing strings case-insensitively */
+const TRUTHY_VALUES = new Set<unknown>(["true", "1", "yes", "on", 1]);
+const FALSY_VALUES = new Set<unknown>(["false", "0", "no", "off", "", 0]);
+
+export class BooleanSchemaType extends BaseSchema<unknown, boolean> {
+  readonly type: SchemaPrimitive = "boolean";
+
+  constructor() {
+    super();
+    this.jsonSchema = { type: "boolean" };
+  }
+
+  primitive(): SchemaPrimitive {
+    return this.type;
+  }
+
+  protected _validate(value: unknown): ValidationResult<boolean> {
+    if (this._coerce && typeof value !== "boolean") {
+      const key = typeof value === "string" ? value.toLowerCase() : value;
+      if (TRUTHY_VALUES.has(key)) {
+        value = true;
+      } else if (FALSY_VALUES.has(key)) {
+        value = false;
+      }
+    }
+    if (typeof value !== "boolean") {
+      return { issues: [typeMismatch("boolean", value)] };
+    }
+    return { value };
+  }
+}
+
+function dateBound(date: number | string | Date, method: string): number {
+  const time = new Date(date).getTime();
+  if (Number.isNaN(time)) {
+    throw new Error(`${method}() requires a valid date`);
+  }
+  return time;
+}
+
+export class DateSchemaType extends BaseSchema<unknown, Date> {
+  readonly type = "date";
+  private _minDate?: number;
+  private _maxDate?: number;
+
+  constructor() {
+    super();
+    this.jsonSchema = { type: "string", format: "date-time" };
+  }
+
+  minDate(timestamp: number | string | Date): DateSchemaType {
+    const schema = new DateSchemaType();
+    Object.assign(schema, this);
+    schema._minDate = dateBound(timestamp, "minDate");
+    schema.jsonSchema = {
+      ...this.jsonSchema,
+      "x-minDate": new Date(schema._minDate).toISOString(),
+    };
+    return schema;
+  }
+
+  maxDate(timestamp: number | string | Date): DateSchemaType {
+    const schema = new DateSchemaType();
+    Object.assign(schema, this);
+    schema._maxDate = dateBound(timestamp, "maxDate");
+    schema.jsonSchema = {
+      ...this.jsonSchema,
+      "x-maxDate": new Date(schema._maxDate).toISOString(),
+    };
+    return schema;
+  }
+
+  after(date: number | string | Date): DateSchemaType {
+    return this.minDate(date);
+  }
+
+  before(date: number | string | Date): DateSchemaType {
+    return this.maxDate(date);
+  }
+
+  getType(): string {
+    return this.type;
+  }
+
+  protected _validate(value: unknown): ValidationResult<Date> {
+    if (this._coerce && (typeof value === "string" || typeof value === "number")) {
+      value = new Date(value);
+    }
+    if (!(value instanceof Date)) {
+      return { issues: [typeMismatch("Date", value)] };
+    }
+    const time = value.getTime();
+    if (Number.isNaN(time)) {
+      const details = { expected: "Date", received: "Invalid Date" };
+      return { issues: [builtinIssue("invalid_date", "Invalid Date", details)] };
+    }
+    if (this._minDate !== undefined && time < this._minDate) {
+      const min = new Date(this._minDate).toISOString();
+      return { issues: [builtinIssue("too_small", `Date earlier than ${min}`, { min })] };
+    }
+    if (this._maxDate !== undefined && time > this._maxDate) {
+      const max = new Date(this._maxDate).toISOString();
+      return { issues: [builtinIssue("too_big", `Date later than ${max}`, { max })] };
+    }
+    return { value };
+  }
+}
+
+export class AnySchemaType extends BaseSchema<unknown, any> {
+  readonly type: SchemaPrimitive = "any";
+
+  getType(): string {
+    return this.type;
+  }
+
+  protected _validate(value: unknown): ValidationResult<any> {
+    return { value };
+  }
+}
+
+export class LiteralSchema<I, T extends string | number | boolean> extends BaseSchema<I, T> {
+  readonly value: T;
+
+  constructor(value: T) {
+    super();
+    this.value = value;
+    this.jsonSchema = {
+      const: value,
+      type: typeof value as "string" | "number" | "boolean",
+    };
+  }
+
+  protected _validate(value: unknown): ValidationResult<T> {
+    if (value !== this.value) {
+      const received =
+        typeof value === "function" || (typeof value === "object" && value !== null)
+          ? typeName(value)
+          : String(value);
+      return {
+        issues: [
+          builtinIssue(
+            "invalid_literal",
+            `Expected literal value ${this.value}, received ${received}`,
+            { expected: String(this.value), received },
+          ),
+        ],
+      };
+    }
+    return { value: value as T };
+  }
+}
+
+export class OptionalSchema<I, O> extends BaseSchema<I, O | undefined> {
+  private readonly innerSchema: Schema<I, O>;
+
+  constructor(schema: Schema<I, O>) {
+    super();
+    this.innerSchema = schema;
+    this.jsonSchema = { ...schema.jsonSchema };
+  }
+
+  unwrap(): Schema<I, O> {
+    return this.innerSchema;
+  }
+
+  getType(): string {
+    return this.innerSchema.getType();
+  }
+
+  isOptional(): boolean {
+    return true;
+  }
+
+  protected _validate(
+    value: unknown,
+    options?: StandardSchemaV1.Options,
+  ): ValidationResult<O | undefined> {
+    if (value === undefined) {
+      return { value: undefined };
+    }
+
+    const result = this.innerSchema["~standard"].validate(value, options);
+    return result as ValidationResult<O>;
+  }
+}
+
+export class DefaultSchema<I, O> extends BaseSchema<I, O> {
+  private readonly innerSchema: Schema<I, any>;
+  private readonly defaultValue: O;
+
+  constructor(schema: Schema<I, any>, defaultValue: O) {
+    super();
+    this.innerSchema = schema;
+    this.defaultValue = defaultValue;
+    this.jsonSchema = { ...schema.jsonSchema, default: defaultValue };
+  }
+
+  getType(): string {
+    return this.innerSchema.getType();
+  }
+
+  isOptional(): boolean {
+    return true;
+  }
+
+  protected _validate(value: unknown, options?: StandardSchemaV1.Options): ValidationResult<O> {
+    const result = this.innerSchema["~standard"].validate(
+      value === undefined ? this.defaultValue : value,
+      options,
+    );
+    return result as ValidationResult<O>;
+  }
+}
+
+export class CatchSchema<I, O> extends BaseSchema<I, O> {
+  private readonly innerSchema: Schema<I, O>;
+  private readonly fallback: O;
+
+  constructor(schema: Schema<I, O>, fallback: O) {
+    super();
+    this.innerSchema = schema;
+    this.fallback = fallback;
+    this.jsonSchema = { ...schema.jsonSchema };
+  }
+
+  getType(): string {
+    return this.innerSchema.getType();
+  }
+
+  protected _validate(value: unknown, options?: StandardSchemaV1.Options): ValidationResult<O> {
+    const start = pendingCount(options);
+    const result = this.innerSchema["~standard"].validate(value, options) as ValidationResult<O>;
+    if ("issues" in result) {
+      discardPending(options, start);
+      return { value: this.fallback };
+    }
+    return result;
+  }
+}
+
+export class TransformSchema<I, O> extends BaseSchema<I, O> {
+  private readonly innerSchema: Schema<I, any>;
+  private readonly fn: (value: any) => O;
+
+  constructor(schema: Schema<I, any>, fn: (value: any) => O) {
+    super();
+    this.innerSchema = schema;
+    this.fn = fn;
+    this.jsonSchema = { ...schema.jsonSchema };
+  }
+
+  protected _validate(value: unknown, options?: StandardSchemaV1.Options): ValidationResult<O> {
+    const result = this.innerSchema["~standard"].validate(value, options) as ValidationResult<any>;
+    if (result.issues) {
+      return result;
+    }
+    return { value: this.fn(result.value) };
+  }
+}
+
+export class PipeSchema<I, O> extends BaseSchema<I, O> {
+  private readonly innerSchema: Schema<I, any>;
+  private readonly nextSchema: Schema<any, O>;
+
+  constructor(schema: Schema<I, any>, next: Schema<any, O>) {
+    super();
+    this.innerSchema = schema;
+    this.nextSchema = next;
+    this.jsonSchema = { ...schema.jsonSchema };
+  }
+
+  protected _validate(value: unknown, options?: StandardSchemaV1.Options): ValidationResult<O> {
+    const result = this.innerSchema["~standard"].validate(value, options) as ValidationResult<any>;
+    if (result.issues) {
+      return result;
+    }
+    return this.nextSchema["~standard"].validate(result.value, options) as ValidationResult<O>;
+  }
+}
+
+export class NullableSchema<I, O> extends BaseSchema<I, O | null> {
+  private readonly innerSchema: Schema<I, O>;
+
+  constructor(schema: Schema<I, O>) {
+    super();
+    this.innerSchema = schema;
+    const inner = schema.jsonSchema;
+    this.jsonSchema =
+      typeof inner.type === "string" && !("const" in inner) && !("enum" in inner)
+        ? { ...inner, type: [inner.type, "null"] }
+        : { anyOf: [inner, { type: "null" }] };
+  }
+
+  getType(): string {
+    return this.innerSchema.getType();
+  }
+
+  protected _validate(
+    value: unknown,
+    options?: StandardSchemaV1.Options,
+  ): ValidationResult<O | null> {
+    if (value === null) {
+      return { value: null };
+    }
+
+    const result = this.innerSchema["~standard"].validate(value, options);
+    return result as ValidationResult<O>;
+  }
+}
+
+export class NullSchemaType extends BaseSchema<unknown, null> {
+  readonly type = "null";
+  constructor() {
+    super();
+    this.jsonSchema = { type: "null" };
+  }
+
+  protected _validate(value: unknown): ValidationResult<null> {
+    if (value !== null) {
+      return { issues: [typeMismatch("null", value)] };
+    }
+    return { value: null };
+  }
+}
+
+export class NeverSchemaType extends BaseSchema<unknown, never> {
+  readonly type = "never";
+  constructor() {
+    super();
+    this.jsonSchema = { not: {} };
+  }
+
+  getType(): string {
+    return this.type;
+  }
+
+  protected _validate(value: unknown): ValidationResult<never> {
+    const details = { expected: "never", received: typeName(value) };
+    return { issues: [builtinIssue("invalid_type", "No value is valid", details)] };
+  }
+}
+
+export class VoidSchemaType extends BaseSchema<unknown, void> {
+  readonly type = "void";
+
+  getType(): string {
+    return this.type;
+  }
+
+  protected _validate(value: unknown): ValidationResult<void> {
+    if (value !== undefined) {
+      return { issues: [typeMismatch("undefined", value)] };
+    }
+    return { value: undefined };
+  }
+}
+
+export class FunctionSchemaType extends BaseSchema<unknown, (...args: any[]) => unknown> {
+  readonly type = "function";
+  private _minArgs?: number;
+
+  constructor() {
+    super();
+    this.jsonSchema = { type: "object", "x-type": "function" };
+  }
+
+  minArgs(n: number): FunctionSchemaType {
+    const schema = new FunctionSchemaType();
+    Object.assign(schema, this);
+    schema._minArgs = n;
+    return schema;
+  }
+
+  getType(): string {
+    return this.type;
+  }
+
+  protected _validate(value: unknown): ValidationResult<(...args: any[]) => unknown> {
+    if (typeof value !== "function") {
+      return { issues: [typeMismatch("function", value)] };
+    }
+    if (this._minArgs !== undefined && value.length < this._minArgs) {
+      const expected = `at least ${this._minArgs} argument(s)`;
+      const message = `Function must accept ${expected}, received ${value.length}`;
+      return { issues: [builtinIssue("too_small", message, { min: this._minArgs })] };
+    }
+    return { value: value as (...args: any[]) => unknown };
+  }
+}
+
+/**
+ * Union of schemas. On failure, reports the issues of the closest option (one that matched
+ * at the root with the fewest nested issues), or every root mismatch otherwise.
+ */
+export class UnionSchema<I, O> extends BaseSchema<I, O> {
+  private readonly schemas: Schema<I, any>[];
+  private readonly probes: (string | undefined)[];
+  constructor(...schemas: Schema<I, any>[]) {
+    super();
+    this.schemas = schemas;
+    this.probes = schemas.map(runtimeTypeOf);
+    const values = literalValues(schemas);
+    this.jsonSchema = values ? enumJsonSchema(values) : { anyOf: schemas.map((s) => s.jsonSchema) };
+  }
+
+  protected _validate(value: unknown, options?: StandardSchemaV1.Options): ValidationResult<O> {
+    const rootIssues: ValidationIssue[] = [];
+    let bestIssues: readonly ValidationIssue[] | undefined;
+    const received = typeName(value);
+    for (const [index, schema] of this.schemas.entries()) {
+      const probe = this.probes[index];
+      if (probe !== undefined && probe !== received) {
+        rootIssues.push(typeMismatch(probe, value));
+        continue;
+      }
+      const start = pendingCount(options);
+      const result = schema["~standard"].validate(value, options) as ValidationResult<any>;
+      if (!result.issues) {
+        return { value: result.value };
+      }
+      discardPending(options, start);
+      if (result.issues.some((issue) => !issue.path?.length)) {
+        rootIssues.push(...result.issues);
+      } else if (!bestIssues || result.issues.length < bestIssues.length) {
+        bestIssues = result.issues;
+      }
+    }
+    return { issues: bestIssues ?? rootIssues };
+  }
+}
+
+/** Values of a union made only of plain literals, which JSON Schema can express as one `enum` */
+function literalValues(schemas: Schema<any, any>[]): unknown[] | undefined {
+  const values: unknown[] = [];
+  for (const schema of schemas) {
+    const { const: _const, type: _type, ...rest } = schema.jsonSchema;
+    if (!(schema instanceof LiteralSchema) || Reflect.ownKeys(rest).length > 0) {
+      return undefined;
+    }
+    values.push(schema.value);
+  }
+  return values.length > 0 ? values : undefined;
+}
+
+function enumJsonSchema(values: readonly unknown[]): Record<string, unknown> {
+  const types = new Set(values.map((value) => typeof value));
+  return types.size === 1 ? { type: [...types][0], enum: [...values] } : { enum: [...values] };
+}
+
+/**
+ * Runtime type a union option requires, letting the union reject it without running the full
+ * validation; `undefined` when the option may accept other types, e.g. through coercion
+ */
+function runtimeTypeOf(schema: Schema<any, any>): string | undefined {
+  const { _coerce, _preprocess } = schema as unknown as { _coerce: boolean; _preprocess?: unknown };
+  if (_coerce || _preprocess) {
+    return undefined;
+  }
+  if (schema instanceof StringSchemaType) {
+    return "string";
+  }
+  if (schema instanceof NumberSchemaType) {
+    return "number";
+  }
+  if (schema instanceof BooleanSchemaType) {
+    return "boolean";
+  }
+  if (schema instanceof BigIntSchemaType) {
+    return "bigint";
+  }
+  if (schema instanceof ObjectSchemaType) {
+    return "object";
+  }
+  if (schema instanceof ArraySchema) {
+    return "array";
+  }
+  return undefined;
+}
+
+/** Union of literal values, reporting a single issue that lists every allowed value */
+export class LiteralsSchema<I, T extends string | number | boolean> extends UnionSchema<I, T> {
+  readonly values: readonly T[];
+
+  constructor(values: readonly T[]) {
+    super(...values.map((value) => new LiteralSchema<I, T>(value)));
+    this.values = values;
+  }
+
+  protected _validate(value: unknown): ValidationResult<T> {
+    if (!this.values.includes(value as T)) {
+      const expected = this.values.map((v) => JSON.stringify(v)).join(" | ");
+      const shown = ["string", "number", "boolean"].includes(typeof value)
+        ? JSON.stringify(value)
+        : typeName(value);
+      return {
+        issues: [
+          builtinIssue("invalid_literal", `Expected one of ${expected}, received ${shown}`, {
+            expected,
+            received: typeName(value),
+          }),
+        ],
+      };
+    }
+    return { value: value as T };
+  }
+}
+
+export class BrandedSchema<I, O> extends BaseSchema<I, O> {
+  private readonly innerSchema: Schema<I, any>;
+  readonly brandName: string;
+
+  constructor(schema: Schema<I, any>, brandName: string) {
+    super();
+    this.innerSchema = schema;
+    this.brandName = brandName;
+    this.jsonSchema = { ...schema.jsonSchema, "x-brand": brandName };
+  }
+
+  getType(): string {
+    return this.innerSchema.getType();
+  }
+
+  protected _validate(value: unknown, options?: StandardSchemaV1.Options): ValidationResult<O> {
+    return this.innerSchema["~standard"].validate(value, options) as ValidationResult<O>;
+  }
+}
+
+/**
+ * Promise whose resolved value is validated by `validateAsync()`; synchronous validation
+ * only checks that the value is a promise. The output is a promise of the parsed value that
+ * rejects with a `ValidationError` when the resolved value is invalid
+ */
+export class PromiseSchema<I, O> extends BaseSchema<I, Promise<O>> {
+  readonly type = "promise";
+  private readonly innerSchema: BaseSchema<I, O>;
+
+  constructor(schema: BaseSchema<I, O>) {
+    super();
+    this.innerSchema = schema;
+    this.jsonSchema = { ...schema.jsonSchema };
+  }
+
+  unwrap(): BaseSchema<I, O> {
+    return this.innerSchema;
+  }
+
+  getType(): string {
+    return this.type;
+  }
+
+  protected _validate(
+    value: unknown,
+    options?: StandardSchemaV1.Options,
+  ): ValidationResult<Promise<O>> {
+    if (!(value instanceof Promise)) {
+      return { issues: [typeMismatch("Promise", value)] };
+    }
+    const outcome = this._resolve(value, options);
+    const issues = outcome.then((result) => (result.issues ? [...result.issues] : []));
+    pendingChecks(options)?.push({ path: [], issues });
+    const output = outcome.then((result) =>
+      result.issues ? Promise.reject(new ValidationError(result.issues)) : result.value,
+    );
+    // Failures are reported through the pending check; callers may never await the output
+    output.catch(() => {});
+    return { value: output };
+  }
+
+  private async _resolve(
+    promise: Promise<unknown>,
+    options?: StandardSchemaV1.Options,
+  ): Promise<ValidationResult<O>> {
+    let resolved: unknown;
+    try {
+      resolved = await promise;
+    } catch (error) {
+      const message = error instanceof Error ? error.message : String(error);
+      return { issues: [{ message: `Promise rejected: ${message}`, code: "custom" }] };
+    }
+    const { pending: _pending, ...validationOptions } = options?.libraryOptions ?? {};
+    return this.innerSchema.validateAsync(resolved, validationOptions as ValidationOptions);
+  }
+}
+
+export class IntersectionSchema<I, O> extends BaseSchema<I, O> {
+  private readonly left: Schema<I, any>;
+  private readonly right: Schema<any, any>;
+
+  constructor(left: Schema<I, any>, right: Schema<any, any>) {
+    super();
+    this.left = left;
+    this.right = right;
+    this.jsonSchema = { allOf: [left.jsonSchema, right.jsonSchema] };
+  }
+
+  protected _validate(value: unknown, options?: StandardSchemaV1.Options): ValidationResult<O> {
+    const left = this.left["~standard"].validate(value, options) as ValidationResult<any>;
+    if (left.issues && isAbortEarly(options)) {
+      return left;
+    }
+    const right = this.right["~standard"].validate(value, options) as ValidationResult<any>;
+    if (left.issues || right.issues) {
+      return { issues: [...(left.issues ?? []), ...(right.issues ?? [])] };
+    }
+    if (isPlainObject(left.value) && isPlainObject(right.value)) {
+      return { value: { ...left.value, ...right.value } as O };
+    }
+    return { value: right.value };
+  }
+}
+
+export class LazySchema<I, O> extends BaseSchema<I, O> {
+  private readonly getter: () => Schema<I, O>;
+  private resolved?: Schema<I, O>;
+  private depth = 0;
+
+  constructor(getter: () => Schema<I, O>) {
+    super();
+    this.getter = getter;
+  }
+
+  getType(): string {
+    this.resolved ??= this.getter();
+    return this.resolved.getType();
+  }
+
+  protected _validate(value: unknown, options?: StandardSchemaV1.Options): ValidationResult<O> {
+    if (this.depth >= maxDepth(options)) {
+      return { issues: [{ message: "Maximum recursion depth exceeded", code: "recursion_limit" }] };
+    }
+    this.resolved ??= this.getter();
+    this.depth++;
+    try {
+      return this.resolved["~standard"].validate(value, options) as ValidationResult<O>;
+    } finally {
+      this.depth--;
+    }
+  }
+}
+
+export class DiscriminatedUnionSchema<I, O> extends BaseSchema<I, O> {
+  private readonly discriminator: string;
+  private readonly schemas: ObjectSchemaType<any>[];
+  private readonly options = new Map<unknown, ObjectSchemaType<any>>();
+
+  constructor(discriminator: string, schemas: ObjectSchemaType<any>[]) {
+    super();
+    this.discriminator = discriminator;
+    this.schemas = schemas;
+    for (const schema of schemas) {
+      const literal = schema.definition[discriminator];
+      if (!(literal instanceof LiteralSchema)) {
+        throw new Error(
+          `h.discriminatedUnion() requires every option to define "${discriminator}" as a literal.`,
+        );
+      }
+      this.options.set(literal.value, schema);
+    }
+    this.jsonSchema = {
+      oneOf: schemas.map((s) => s.jsonSchema),
+      discriminator: { propertyName: discriminator },
+    };
+  }
+
+  protected _validate(value: unknown, options?: StandardSchemaV1.Options): ValidationResult<O> {
+    if (typeof value !== "object" || value === null || Array.isArray(value)) {
+      return { issues: [typeMismatch("object", value)] };
+    }
+
+    const schema = this.options.get((value as Record<string, unknown>)[this.discriminator]);
+    if (!schema) {
+      const expected = [...this.options.keys()].map((v) => JSON.stringify(v)).join(" | ");
+      const received = typeName((value as Record<string, unknown>)[this.discriminator]);
+      const issue = builtinIssue(
+        "invalid_union_discriminator",
+        `Invalid discriminator value. Expected ${expected}`,
+        { expected, received },
+      );
+      return { issues: [{ ...issue, path: [this.discriminator] }] };
+    }
+
+    return schema["~standard"].validate(value, options) as ValidationResult<O>;
+  }
+}
+
+export class ArraySchema<I, O extends any[]> extends BaseSchema<I, O> {
+  private readonly innerSchema: Schema<I, O[number]>;
+  private _minItems?: number;
+  private _maxItems?: number;
+  private _unique = false;
+  private _uniqueBy?: (value: O[number]) => unknown;
+  private _order?: { order: SortOrder; compare: (a: O[number], b: O[number]) => number };
+  private _overrides = new Map<number, Schema<any, any>>();
+
+  constructor(schema: Schema<I, O[number]>) {
+    super();
+    this.innerSchema = schema;
+    this.jsonSchema = { type: "array", items: schema.jsonSchema };
+  }
+
+  /**
+   * Get the schema applied to each item
+   * @returns {Schema<I, O[number]>} Schema validating a single item
+   */
+  element(): Schema<I, O[number]> {
+    return this.innerSchema;
+  }
+
+  min(n: number): ArraySchema<I, O> {
+    const schema = this._clone();
+    schema._minItems = n;
+    schema.jsonSchema = { ...this.jsonSchema, minItems: n };
+    return schema;
+  }
+
+  max(n: number): ArraySchema<I, O> {
+    const schema = this._clone();
+    schema._maxItems = n;
+    schema.jsonSchema = { ...this.jsonSchema, maxItems: n };
+    return schema;
+  }
+
+  length(n: number): ArraySchema<I, O> {
+    return this.min(n).max(n);
+  }
+
+  nonempty(): ArraySchema<I, O> {
+    return this.min(1);
+  }
+
+  unique(key?: (value: O[number]) => unknown): ArraySchema<I, O> {
+    const schema = this._clone();
+    schema._unique = true;
+    schema._uniqueBy = key;
+    schema.jsonSchema = { ...this.jsonSchema, uniqueItems: true };
+    return schema;
+  }
+
+  ascending(compare?: (a: O[number], b: O[number]) => number): ArraySchema<I, O> {
+    return this._sorted("ascending", compare);
+  }
+
+  descending(compare?: (a: O[number], b: O[number]) => number): ArraySchema<I, O> {
+    return this._sorted("descending", compare);
+  }
+
+  private _sorted(
+    order: SortOrder,
+    compare: (a: O[number], b: O[number]) => number = compareNatural,
+  ): ArraySchema<I, O> {
+    const schema = this._clone();
+    schema._order = { order, compare };
+    schema.jsonSchema = { ...this.jsonSchema, "x-sorted": order };
+    return schema;
+  }
+
+  overrideAt<T>(index: number, override: Schema<any, T>): ArraySchema<I, (O[number] | T)[]> {
+    const schema = this._clone() as unknown as ArraySchema<I, (O[number] | T)[]>;
+    schema._overrides = new Map(this._overrides).set(index, override);
+    const last = Math.max(...schema._overrides.keys());
+    const prefixItems = Array.from(
+      { length: last + 1 },
+      (_, i) => (schema._overrides.get(i) ?? this.innerSchema).jsonSchema,
+    );
+    schema.jsonSchema = { ...this.jsonSchema, prefixItems };
+    return schema;
+  }
+
+  protected _validate(value: unknown, options?: StandardSchemaV1.Options): ValidationResult<O> {
+    if (this._coerce && !Array.isArray(value) && value !== undefined) {
+      value = [value];
+    }
+
+    if (!Array.isArray(value)) {
+      return { issues: [typeMismatch("array", value)] };
+    }
+
+    if (this._minItems !== undefined && value.length < this._minItems) {
+      const message = `Array must contain at least ${this._minItems} element(s)`;
+      return { issues: [builtinIssue("too_small", message, { min: this._minItems })] };
+    }
+
+    if (this._maxItems !== undefined && value.length > this._maxItems) {
+      const message = `Array must contain at most ${this._maxItems} element(s)`;
+      return { issues: [builtinIssue("too_big", message, { max: this._maxItems })] };
+    }
+
+    const output: unknown[] = [];
+    const issues: ValidationIssue[] = [];
+    for (let index = 0; index < value.length; index++) {
+      const schema = this._overrides.get(index) ?? this.innerSchema;
+      const start = pendingCount(options);
+      const result = schema["~standard"].validate(value[index], options) as ValidationResult<any>;
+      prefixPending(options, start, [index]);
+      if (result.issues) {
+        issues.push(
+          ...result.issues.map((issue) => ({
+            ...issue,
+            path: issue.path ? [index, ...issue.path] : [index],
+          })),
+        );
+        if (hasEnoughIssues(issues, options)) {
+          break;
+        }
+      } else {
+        output.push(result.value);
+      }
+    }
+
+    if (issues.length > 0) {
+      return { issues };
+    }
+
+    if (this._unique) {
+      const index = findDuplicateIndex(output, this._uniqueBy);
+      if (index !== -1) {
+        const issue = builtinIssue("not_unique", "Array must contain unique elements");
+        return { issues: [{ ...issue, path: [index] }] };
+      }
+    }
+
+    if (this._order) {
+      const { order, compare } = this._order;
+      const index = findUnsortedIndex(output, order, compare);
+      if (index !== -1) {
+        const message = `Array must be sorted in ${order} order`;
+        const issue = builtinIssue("not_sorted", message, { expected: order });
+        return { issues: [{ ...issue, path: [index] }] };
+      }
+    }
+
+    return { value: output as O };
+  }
+}
+
+export class TupleSchema<I, O extends any[]> extends BaseSchema<I, O> {
+  private readonly items: Schema<any, any>[];
+  private restSchema?: Schema<any, any>;
+
+  constructor(items: Schema<any, any>[], rest?: Schema<any, any>) {
+    super();
+    this.items = items;
+    this.restSchema = rest;
+    this.jsonSchema = {
+      type: "array",
+      prefixItems: items.map((item) => item.jsonSchema),
+      items: rest ? rest.jsonSchema : false,
+      minItems: items.length,
+      ...(rest ? {} : { maxItems: items.length }),
+    };
+  }
+
+  /**
+   * Allow extra elements after the positional items, validated against a schema
+   * @param {Schema<any, T>} schema - Schema for the remaining elements
+   * @returns {TupleSchema<I, [...O, ...T[]]>} Tuple schema with a rest element
+   */
+  rest<T>(schema: Schema<any, T>): TupleSchema<I, [...O, ...T[]]> {
+    const tuple = this._clone() as unknown as TupleSchema<I, [...O, ...T[]]>;
+    tuple.restSchema = schema;
+    const { maxItems: _maxItems, ...jsonSchema } = this.jsonSchema;
+    tuple.jsonSchema = { ...jsonSchema, items: schema.jsonSchema };
+    return tuple;
+  }
+
+  protected _validate(value: unknown, options?: StandardSchemaV1.Options): ValidationResult<O> {
+    if (!Array.isArray(value)) {
+      return { issues: [typeMismatch("array", value)] };
+    }
+
+    if (
+      value.length < this.items.length ||
+      (!this.restSchema && value.length > this.items.length)
+    ) {
+      const tooSmall = value.length < this.items.length;
+      const expected = this.restSchema ? `at least ${this.items.length}` : this.items.length;
+      const message = `Expected tuple of ${expected} element(s), received ${value.length}`;
+      const details = tooSmall ? { min: this.items.length } : { max: this.items.length };
+      return { issues: [builtinIssue(tooSmall ? "too_small" : "too_big", message, details)] };
+    }
+
+    const output: unknown[] = [];
+    const issues: ValidationIssue[] = [];
+    for (let index = 0; index < value.length; index++) {
+      const schema = this.items[index] ?? this.restSchema!;
+      const start = pendingCount(options);
+      const result = schema["~standard"].validate(value[index], options) as ValidationResult<any>;
+      prefixPending(options, start, [index]);
+      if (result.issues) {
+        issues.push(
+          ...result.issues.map((issue) => ({
+            ...issue,
+            path: issue.path ? [index, ...issue.path] : [index],
+          })),
+        );
+        if (hasEnoughIssues(issues, options)) {
+          break;
+        }
+      } else {
+        output.push(result.value);
+      }
+    }
+
+    if (issues.length > 0) {
+      return { issues };
+    }
+    return { value: output as O };
+  }
+}
+
+export class SetSchema<I, O> extends BaseSchema<I, Set<O>> {
+  private readonly innerSchema: Schema<I, O>;
+  private _minSize?: number;
+  private _maxSize?: number;
+
+  constructor(schema: Schema<I, O>) {
+    super();
+    this.innerSchema = schema;
+    this.jsonSchema = { type: "array", items: schema.jsonSchema, uniqueItems: true };
+  }
+
+  min(n: number): SetSchema<I, O> {
+    const schema = this._clone();
+    schema._minSize = n;
+    schema.jsonSchema = { ...this.jsonSchema, minItems: n };
+    return schema;
+  }
+
+  max(n: number): SetSchema<I, O> {
+    const schema = this._clone();
+    schema._maxSize = n;
+    schema.jsonSchema = { ...this.jsonSchema, maxItems: n };
+    return schema;
+  }
+
+  getType(): string {
+    return "set";
+  }
+
+  protected _validate(
+    value: unknown,
+    options?: StandardSchemaV1.Options,
+  ): ValidationResult<Set<O>> {
+    if (!(value instanceof Set)) {
+      return { issues: [typeMismatch("Set", value)] };
+    }
+
+    if (this._minSize !== undefined && value.size < this._minSize) {
+      const message = `Set must contain at least ${this._minSize} element(s)`;
+      return { issues: [builtinIssue("too_small", message, { min: this._minSize })] };
+    }
+
+    if (this._maxSize !== undefined && value.size > this._maxSize) {
+      const message = `Set must contain at most ${this._maxSize} element(s)`;
+      return { issues: [builtinIssue("too_big", message, { max: this._maxSize })] };
+    }
+
+    const output = new Set<O>();
+    const issues: ValidationIssue[] = [];
+    let index = 0;
+    for (const item of value) {
+      const start = pendingCount(options);
+      const result = this.innerSchema["~standard"].validate(item, options) as ValidationResult<O>;
+      prefixPending(options, start, [index]);
+      if (result.issues) {
+        issues.push(
+          ...result.issues.map((issue) => ({
+            ...issue,
+            path: issue.path ? [index, ...issue.path] : [index],
+          })),
+        );
+        if (hasEnoughIssues(issues, options)) {
+          break;
+        }
+      } else {
+        output.add(result.value);
+      }
+      index++;
+    }
+
+    if (issues.length > 0) {
+      return { issues };
+    }
+    return { value: output };
+  }
+}
+
+export class MapSchema<I, K, V> extends BaseSchema<I, Map<K, V>> {
+  private readonly keySchema: Schema<I, K>;
+  private readonly valueSchema: Schema<I, V>;
+
+  constructor(keySchema: Schema<I, K>, valueSchema: Schema<I, V>) {
+    super();
+    this.keySchema = keySchema;
+    this.valueSchema = valueSchema;
+    this.jsonSchema = {
+      type: "object",
+      ...(keySchema.jsonSchema.type === "string" ? { propertyNames: keySchema.jsonSchema } : {}),
+      additionalProperties: valueSchema.jsonSchema,
+    };
+  }
+
+  getType(): string {
+    return "map";
+  }
+
+  protected _validate(
+    value: unknown,
+    options?: StandardSchemaV1.Options,
+  ): ValidationResult<Map<K, V>> {
+    if (!(value instanceof Map)) {
+      return { issues: [typeMismatch("Map", value)] };
+    }
+
+    const output = new Map<K, V>();
+    const issues: ValidationIssue[] = [];
+    let index = 0;
+    for (const [entryKey, entryValue] of value) {
+      const keyStart = pendingCount(options);
+      const key = this.keySchema["~standard"].validate(entryKey, options) as ValidationResult<K>;
+      prefixPending(options, keyStart, [index, "key"]);
+      if (key.issues) {
+        issues.push(
+          ...key.issues.map((issue) => ({
+            ...issue,
+            path: [index, "key", ...(issue.path ?? [])],
+          })),
+        );
+      }
+      const valueStart = pendingCount(options);
+      const val = this.valueSchema["~standard"].validate(
+        entryValue,
+        options,
+      ) as ValidationResult<V>;
+      prefixPending(options, valueStart, [index, "value"]);
+      if (val.issues) {
+        issues.push(
+          ...val.issues.map((issue) => ({
+            ...issue,
+            path: [index, "value", ...(issue.path ?? [])],
+          })),
+        );
+      }
+      if (hasEnoughIssues(issues, options)) {
+        break;
+      }
+      if (!key.issues && !val.issues) {
+        output.set(key.value, val.value);
+      }
+      index++;
+    }
+
+    if (issues.length > 0) {
+      return { issues };
+    }
+    return { value: output };
+  }
+}
+
+export class InstanceOfSchema<I, O> extends BaseSchema<I, O> {
+  private readonly innerSchema: Schema<I, any>;
+  private readonly classConstructor: new (
+    ...args: any[]
+  ) => any;
+
+  constructor(schema: Schema<I, any>, classConstructor: new (...args: any[]) => any) {
+    super();
+    this.innerSchema = schema;
+    this.classConstructor = classConstructor;
+    this.jsonSchema = { ...schema.jsonSchema, instanceOf: classConstructor.name };
+  }
+
+  protected _validate(value: unknown, options?: StandardSchemaV1.Options): ValidationResult<O> {
+    if (!(value instanceof this.classConstructor)) {
+      const { name } = this.classConstructor;
+      const details = { expected: name, received: typeName(value) };
+      return { issues: [builtinIssue("invalid_type", `Expected instance of ${name}`, details)] };
+    }
+
+    const result = this.innerSchema["~standard"].validate(value, options);
+    return result as ValidationResult<O>;
+  }
+}
+
+export class ObjectSchemaType<T extends Record<string, unknown>> extends BaseSchema<unknown, T> {
+  readonly definition: SchemaDefinition;
+  private nested: Record<string, ObjectSchemaType<any>> = {};
+  private _catchall?: BaseSchema<any, any>;
+  private _strict = false;
+  private _keyTransform?: (key: string) => string;
+  private _keyGroups: { keys: string[]; rule: KeyGroupRule }[] = [];
+  private _conditionals: { trigger: string; when: (value: any) => boolean; keys: string[] }[] = [];
+
+  constructor(definition: SchemaDefinition) {
+    super();
+    this.definition = definition;
+
+    const properties: Record<string, any> = {};
+    const required: string[] = [];
+
+    for (const key in definition) {
+      const schemaItem = definition[key];
+      const isOptional =
+        schemaItem instanceof OptionalSchema || schemaItem instanceof DefaultSchema;
+
+      if (!isOptional) {
+        required.push(key);
+      }
+
+      if (typeof schemaItem === "string") {
+        properties[key] = schemaItem === "any" ? {} : { type: schemaItem };
+      } else if (schemaItem instanceof BaseSchema) {
+        properties[key] = schemaItem.jsonSchema;
+      } else if (typeof schemaItem === "object" && schemaItem !== null) {
+        this.nested[key] = new ObjectSchemaType(schemaItem);
+        properties[key] = this.nested[key].jsonSchema;
+      }
+    }
+
+    this.jsonSchema = {
+      type: "object",
+      properties,
+      required: required.length > 0 ? required : undefined,
+    };
+  }
+
+  /**
+   * Make top-level properties optional
+   * @param {K[]} [keys] - Properties to make optional; every property when omitted
+   * @returns {ObjectSchemaType<Partial<T>>} Object schema with optional properties
+   */
+  partial(): ObjectSchemaType<Partial<T>>;
+  partial<K extends keyof T & string>(
+    keys: K[],
+  ): ObjectSchemaType<Simplify<Omit<T, K> & Partial<Pick<T, K>>>>;
+  partial(keys?: string[]): ObjectSchemaType<any> {
+    const selected = keys ? new Set(keys) : undefined;
+    const definition: SchemaDefinition = {};
+    for (const key in this.definition) {
+      definition[key] =
+        !selected || selected.has(key) ? toOptional(this._property(key)) : this.definition[key]!;
+    }
+    return this._withDefinition(definition);
+  }
+
+  /**
+   * Make optional top-level properties required again
+   * @param {K[]} [keys] - Properties to make required; every property when omitted
+   * @returns {ObjectSchemaType<Required<T>>} Object schema with required properties
+   */
+  required(): ObjectSchemaType<Required<T>>;
+  required<K extends keyof T & string>(
+    keys: K[],
+  ): ObjectSchemaType<Simplify<Omit<T, K> & Required<Pick<T, K>>>>;
+  required(keys?: string[]): ObjectSchemaType<any> {
+    const selected = keys ? new Set(keys) : undefined;
+    const definition: SchemaDefinition = {};
+    for (const key in this.definition) {
+      const schemaItem = this.definition[key]!;
+      definition[key] =
+        schemaItem instanceof OptionalSchema && (!selected || selected.has(key))
+          ? (schemaItem.unwrap() as BaseSchema<any, any>)
+          : schemaItem;
+    }
+    return this._withDefinition(definition);
+  }
+
+  /**
+   * Make every property optional, descending into nested objects and arrays
+   * @returns {ObjectSchemaType<DeepPartial<T>>} Object schema optional at every level
+   */
+  deepPartial(): ObjectSchemaType<DeepPartial<T>> {
+    return this._deepPartial(new WeakMap());
+  }
+
+  private _deepPartial(memo: SchemaMemo): ObjectSchemaType<DeepPartial<T>> {
+    const definition: SchemaDefinition = {};
+    for (const key in this.definition) {
+      const property = mapNestedObjects(
+        this._property(key),
+        (nested) => nested._deepPartial(memo),
+        memo,
+      );
+      definition[key] = toOptional(property);
+    }
+    const schema = this._withDefinition<DeepPartial<T>>(definition);
+    memo.set(this, schema);
+    return schema;
+  }
+
+  /**
+   * Reject undeclared properties, descending into nested objects and arrays; replaces any
+   * `catchall()` so extra keys are rejected at every level
+   * @returns {ObjectSchemaType<T>} Object schema without extra keys at any level
+   */
+  deepStrict(): ObjectSchemaType<T> {
+    return this._deepStrict(new WeakMap());
+  }
+
+  private _deepStrict(memo: SchemaMemo): ObjectSchemaType<T> {
+    const definition: SchemaDefinition = {};
+    for (const key in this.definition) {
+      definition[key] = mapNestedObjects(
+        this._property(key),
+        (nested) => nested._deepStrict(memo),
+        memo,
+      );
+    }
+    const schema = this._withDefinition<T>(definition);
+    schema._catchall = undefined;
+    const strict = schema.strict();
+    memo.set(this, strict);
+    return strict;
+  }
+
+  /**
+   * Keep only the given properties
+   * @param {K[]} keys - Property names to keep
+   * @returns {ObjectSchemaType<Pick<T, K>>} Object schema with the picked properties
+   */
+  pick<K extends keyof T & string>(keys: K[]): ObjectSchemaType<Pick<T, K>> {
+    const definition: SchemaDefinition = {};
+    for (const key of keys) {
+      if (Object.hasOwn(this.definition, key)) {
+        definition[key] = this.definition[key]!;
+      }
+    }
+    return this._withDefinition(definition);
+  }
+
+  /**
+   * Remove the given properties
+   * @param {K[]} keys - Property names to remove
+   * @returns {ObjectSchemaType<Omit<T, K>>} Object schema without the omitted properties
+   */
+  omit<K extends keyof T & string>(keys: K[]): ObjectSchemaType<Omit<T, K>> {
+    const omitted = new Set<string>(keys);
+    const definition: SchemaDefinition = {};
+    for (const key in this.definition) {
+      if (!omitted.has(key)) {
+        definition[key] = this.definition[key]!;
+      }
+    }
+    return this._withDefinition(definition);
+  }
+
+  /**
+   * Combine with another object schema; its properties win on conflicts, while strictness,
+   * catchall, key rules, refinements and annotations come from this schema only
+   * @param {ObjectSchemaType<U>} other - Object schema whose properties are merged in
+   * @returns {ObjectSchemaType<Simplify<Omit<T, keyof U> & U>>} Merged object schema
+   */
+  merge<U extends Record<string, unknown>>(
+    other: ObjectSchemaType<U>,
+  ): ObjectSchemaType<Simplify<Omit<T, keyof U> & U>> {
+    if (!(other instanceof ObjectSchemaType)) {
+      throw new Error("merge() requires an object schema");
+    }
+    return this._withDefinition({ ...this.definition, ...other.definition });
+  }
+
+  /**
+   * Add or override properties, keeping the rest of this schema's configuration
+   * @param {S} schemaDef - Schema definition of the new properties
+   * @returns {ObjectSchemaType<Simplify<Omit<T, keyof S> & InferObject<S>>>} Extended object schema
+   */
+  extend<S extends SchemaDefinition>(
+    schemaDef: S,
+  ): ObjectSchemaType<Simplify<Omit<T, keyof S> & InferObject<S>>> {
+    return this._withDefinition({ ...this.definition, ...schemaDef });
+  }
+
+  /**
+   * Create an enum of the property names
+   * @returns {UnionSchema<unknown, keyof T & string>} Schema matching any property name
+   */
+  keyof(): UnionSchema<unknown, keyof T & string> {
+    const keys = Object.keys(this.definition);
+    if (keys.length === 0) {
+      throw new Error("keyof() requires an object schema with at least one property.");
+    }
+    return new UnionSchema<unknown, keyof T & string>(
+      ...keys.map((key) => new LiteralSchema(key)),
+    );
+  }
+
+  /**
+   * Validate every undeclared property with a schema and keep it on the output
+   * @param {BaseSchema<any, C>} schema - Schema for the extra property values
+   * @returns {ObjectSchemaType<T & Record<string, C>>} Object schema accepting typed extra keys
+   */
+  catchall<C>(schema: BaseSchema<any, C>): ObjectSchemaType<T & Record<string, C>> {
+    const catchall = this._clone() as unknown as ObjectSchemaType<T & Record<string, C>>;
+    catchall._catchall = schema;
+    catchall.jsonSchema = { ...this.jsonSchema, additionalProperties: schema.jsonSchema };
+    return catchall;
+  }
+
+  /**
+   * Reject undeclared properties, reporting every unknown key
+   * @returns {ObjectSchemaType<T>} Object schema without extra keys
+   */
+  strict(): ObjectSchemaType<T> {
+    const strict = this._clone();
+    strict._strict = true;
+    strict.jsonSchema = { ...this.jsonSchema, additionalProperties: false };
+    return strict;
+  }
+
+  /**
+   * Require exactly one of the given properties to be provided
+   * @param {K[]} keys - Mutually exclusive property names, e.g. `["email", "phone"]`
+   * @returns {ObjectSchemaType<T>} Object schema with the exclusivity rule
+   */
+  xor<K extends keyof T & string>(keys: K[]): ObjectSchemaType<T> {
+    return this._keyGroup(keys, "exactlyOne");
+  }
+
+  /**
+   * Allow at most one of the given properties to be provided
+   * @param {K[]} keys - Mutually exclusive property names
+   * @returns {ObjectSchemaType<T>} Object schema with the exclusivity rule
+   */
+  atMostOne<K extends keyof T & string>(keys: K[]): ObjectSchemaType<T> {
+    return this._keyGroup(keys, "atMostOne");
+  }
+
+  /**
+   * Require at least one of the given properties to be provided
+   * @param {K[]} keys - Property names of which one or more must be present
+   * @returns {ObjectSchemaType<T>} Object schema with the presence rule
+   */
+  atLeastOne<K extends keyof T & string>(keys: K[]): ObjectSchemaType<T> {
+    return this._keyGroup(keys, "atLeastOne");
+  }
+
+  /**
+   * Require more properties when a property's parsed value matches, e.g. an address when
+   * `hasShipping` is `true`
+   * @param {K} trigger - Property whose value decides the requirement
+   * @param {(value: T[K]) => boolean} when - Predicate on the trigger's parsed value
+   * @param {(keyof T & string)[]} keys - Properties that must then be provided
+   * @returns {ObjectSchemaType<T>} Object schema with the conditional requirement
+   */
+  requireIf<K extends keyof T & string>(
+    trigger: K,
+    when: (value: T[K]) => boolean,
+    keys: (keyof T & string)[],
+  ): ObjectSchemaType<T> {
+    const schema = this._clone();
+    schema._conditionals = [...this._conditionals, { trigger, when, keys: [...keys] }];
+    return schema;
+  }
+
+  /**
+   * Clone with another set of properties, keeping strictness, catchall, refinements and
+   * annotations; key rules and conditional requirements are kept for the remaining properties
+   */
+  private _withDefinition<U extends Record<string, unknown>>(
+    definition: SchemaDefinition,
+  ): ObjectSchemaType<U> {
+    const shape = new ObjectSchemaType<U>(definition);
+    const schema = this._clone() as unknown as ObjectSchemaType<U>;
+    (schema as { definition: SchemaDefinition }).definition = definition;
+    schema.nested = shape.nested;
+    // Rules naming a property that is no longer declared can't be checked against it
+    const declared = (key: string) => Object.hasOwn(definition, key);
+    schema._keyGroups = this._keyGroups.filter(({ keys }) => keys.every(declared));
+    schema._conditionals = this._conditionals.flatMap((conditional) => {
+      const keys = conditional.keys.filter(declared);
+      return declared(conditional.trigger) && keys.length > 0 ? [{ ...conditional, keys }] : [];
+    });
+    const { [SCHEMA_ID]: id, allOf: _allOf, ...rest } = this.jsonSchema;
+    const groups = schema._keyGroups.map(({ keys, rule }) => keyGroupJsonSchema(keys, rule));
+    const jsonSchema = {
+      ...rest,
+      properties: shape.jsonSchema.properties,
+      required: shape.jsonSchema.required,
+      ...(groups.length > 0 && { allOf: groups }),
+    };
+    schema.jsonSchema = id
+      ? { ...jsonSchema, [SCHEMA_ID]: { name: id.name, definition: jsonSchema } }
+      : jsonSchema;
+    return schema;
+  }
+
+  private _keyGroup(keys: string[], rule: KeyGroupRule): ObjectSchemaType<T> {
+    const schema = this._clone();
+    schema._keyGroups = [...this._keyGroups, { keys: [...keys], rule }];
+    const group = keyGroupJsonSchema(keys, rule);
+    schema.jsonSchema = { ...this.jsonSchema, allOf: [...(this.jsonSchema.allOf ?? []), group] };
+    return schema;
+  }
+
+  /**
+   * Rename input keys before matching them against the properties, e.g. `snake_case` to
+   * `camelCase`; the output and issue paths use the renamed keys, and input keys renamed onto
+   * the same name are reported
+   * @param {(key: string) => string} transform - Maps an input key to its property name
+   * @returns {ObjectSchemaType<T>} Object schema reading renamed keys
+   */
+  keyTransform(transform: (key: string) => string): ObjectSchemaType<T> {
+    const schema = this._clone();
+    schema._keyTransform = transform;
+    return schema;
+  }
+
+  /**
+   * Get the declared property names
+   * @returns {string[]} Property names in declaration order
+   */
+  getKeys(): (keyof T & string)[] {
+    return Object.keys(this.definition) as (keyof T & string)[];
+  }
+
+  /**
+   * Get the schema of a declared property
+   * @param {K} key - Property name
+   * @returns {BaseSchema<unknown, T[K]>} Schema validating that property
+   */
+  shape<K extends keyof T & string>(key: K): BaseSchema<unknown, T[K]> {
+    if (!Object.hasOwn(this.definition, key)) {
+      throw new Error(`Unknown property: ${key}`);
+    }
+    return this._property(key);
+  }
+
+  private _property(key: string): BaseSchema<any, any> {
+    const schemaItem = this.definition[key];
+    if (schemaItem instanceof BaseSchema) {
+      return schemaItem;
+    }
+    if (typeof schemaItem === "string") {
+      return schemaItem === "any"
+        ? new AnySchemaType()
+        : (toStandard(schemaItem).schema as BaseSchema<any, any>);
+    }
+    return this.nested[key]!;
+  }
+
+  protected _validate(value: unknown, options?: StandardSchemaV1.Options): ValidationResult<T> {
+    if (typeof value !== "object" || value === null || Array.isArray(value)) {
+      return { issues: [typeMismatch("object", value)] };
+    }
+
+    const result: Record<string, unknown> = {};
+    const issues: ValidationIssue[] = [];
+    const obj = this._keyTransform
+      ? renameKeys(value as Record<string, unknown>, this._keyTransform, issues)
+      : (value as Record<string, unknown>);
+
+    for (const key in this.definition) {
+      if (hasEnoughIssues(issues, options)) {
+        break;
+      }
+      const schemaItem = this.definition[key];
+      const hasDefault = schemaItem instanceof DefaultSchema;
+      const isOptional = schemaItem instanceof OptionalSchema || hasDefault;
+
+      const provided = Object.hasOwn(obj, key);
+      if (!provided && !isOptional) {
+        const missing = builtinIssue("invalid_type", `Missing required property: ${key}`, {
+          expected: this._property(key).getType(),
+          received: "undefined",
+        });
+        issues.push({ ...missing, path: [key] });
+        continue;
+      }
+
+      if (provided || hasDefault) {
+        if (typeof schemaItem === "string" && schemaItem in ["string", "number", "boolean"]) {
+          const schemaPrimitive = schemaItem as SchemaPrimitive;
+          if (!validatePrimitive(schemaPrimitive, obj[key])) {
+            const message = `Invalid type for property ${key}: expected ${schemaPrimitive}`;
+            const mismatch = builtinIssue("invalid_type", message, {
+              expected: schemaPrimitive,
+              received: typeName(obj[key]),
+            });
+            issues.push({ ...mismatch, path: [key] });
+          } else {
+            result[key] = obj[key];
+          }
+        } else if (schemaItem instanceof BaseSchema) {
+          const start = pendingCount(options);
+          const validationResult = schemaItem["~standard"].validate(
+            obj[key],
+            options,
+          ) as ValidationResult<any>;
+          prefixPending(options, start, [key]);
+          if ("issues" in validationResult) {
+            if (validationResult.issues) {
+              issues.push(
+                ...validationResult.issues.map((issue) => ({
+                  ...issue,
+                  path: issue.path ? [key, ...issue.path] : [key],
+                })),
+              );
+            }
+          } else {
+            result[key] = validationResult.value;
+          }
+        }
+      }
+    }
+
+    if (this._catchall) {
+      for (const key of Object.keys(obj)) {
+        if (Object.hasOwn(this.definition, key)) {
+          continue;
+        }
+        if (hasEnoughIssues(issues, options)) {
+          break;
+        }
+        const start = pendingCount(options);
+        const validationResult = this._catchall["~standard"].validate(
+          obj[key],
+          options,
+        ) as ValidationResult<any>;
+        prefixPending(options, start, [key]);
+        if ("issues" in validationResult) {
+          issues.push(
+            ...validationResult.issues.map((issue) => ({
+              ...issue,
+              path: issue.path ? [key, ...issue.path] : [key],
+            })),
+          );
+        } else {
+          // Defined rather than assigned so a `__proto__` key stays a plain property
+          Object.defineProperty(result, key, {
+            value: validationResult.value,
+            enumerable: true,
+            writable: true,
+            configurable: true,
+          });
+        }
+      }
+    } else if (this._strict) {
+      for (const key of Object.keys(obj)) {
+        if (Object.hasOwn(this.definition, key)) {
+          continue;
+        }
+        if (hasEnoughIssues(issues, options)) {
+          break;
+        }
+        const unrecognized = builtinIssue("unrecognized_keys", `Unrecognized key: ${key}`, {
+          received: key,
+        });
+        issues.push({ ...unrecognized, path: [key] });
+      }
+    }
+
+    for (const { trigger, when, keys } of this._conditionals) {
+      if (hasEnoughIssues(issues, options)) {
+        break;
+      }
+      const invalid = issues.some((issue) => issue.path?.[0] === trigger);
+      if (invalid || !when(result[trigger])) {
+        continue;
+      }
+      for (const key of keys) {
+        if (obj[key] === undefined && result[key] === undefined) {
+          const missing = builtinIssue("invalid_type", `Missing required property: ${key}`, {
+            expected: this._property(key).getType(),
+            received: "undefined",
+          });
+          issues.push({ ...missing, path: [key] });
         }
-        return { index, value: result.value };
-      });
-
-      const errors = results.filter((r) => "issues" in r) as {
-        index: number;
-        issues: StandardSchemaV1.Issue[];
-      }[];
+      }
+    }
 
-      if (errors.length > 0) {
-        return {
-          issues: errors.flatMap((e) => e.issues),
-        };
+    for (const { keys, rule } of this._keyGroups) {
+      if (hasEnoughIssues(issues, options)) {
+        break;
+      }
+      const provided = keys.filter((key) => obj[key] !== undefined).length;
+      const list = keys.join(", ");
+      const details = { expected: list, received: String(provided) };
+      if (rule === "exactlyOne" && provided !== 1) {
+        issues.push(builtinIssue("custom", `Exactly one of ${list} must be provided`, details));
+      } else if (rule === "atMostOne" && provided > 1) {
+        issues.push(builtinIssue("custom", `At most one of ${list} may be provided`, details));
+      } else if (rule === "atLeastOne" && provided === 0) {
+        issues.push(builtinIssue("custom", `At least one of ${list} must be provided`, details));
       }
+    }
 
-      return { value: results.map((r) => ("value" in r ? r.value : null)) as O };
-    },
-    types: {
-      input: {} as I,
-      output: {} as O,
-    },
-  };
+    if (issues.length > 0) {
+      return { issues };
+    }
+
+    return { value: result as T };
+  }
 }
 
-export class InstanceOfSchema<I, O> extends BaseSchema<I, O> {
-  private readonly innerSchema: Schema<I, any>;
-  private readonly classConstructor: new (
-    ...args: any[]
-  ) => any;
+/** How many properties of an `xor()` / `atMostOne()` / `atLeastOne()` group may be provided */
+type KeyGroupRule = "exactlyOne" | "atMostOne" | "atLeastOne";
 
-  constructor(schema: Schema<I, any>, classConstructor: new (...args: any[]) => any) {
-    super();
-    this.innerSchema = schema;
-    this.classConstructor = classConstructor;
-    this.jsonSchema = { ...schema.jsonSchema, instanceOf: classConstructor.name };
+/**
+ * Copy the own keys of `value` under their renamed names. A key renamed onto a name that is
+ * already taken is reported in `issues` and not copied, so the first input key wins.
+ */
+function renameKeys(
+  value: Record<string, unknown>,
+  transform: (key: string) => string,
+  issues: ValidationIssue[],
+): Record<string, unknown> {
+  const renamed: Record<string, unknown> = {};
+  for (const [key, item] of Object.entries(value)) {
+    const name = transform(key);
+    if (Object.hasOwn(renamed, name)) {
+      const message = `Duplicate key after renaming ${key}: ${name}`;
+      issues.push({ ...builtinIssue("custom", message, { received: key }), path: [name] });
+      continue;
+    }
+    // Defined rather than assigned so a `__proto__` key stays a plain property
+    Object.defineProperty(renamed, name, {
+      value: item,
+      enumerable: true,
+      writable: true,
+      configurable: true,
+    });
   }
+  return renamed;
+}
 
-  readonly "~standard": CombinedStandardProps<I, O> = {
-    version: 1,
-    vendor: "h-schema",
-    jsonSchema: {
-      input: () => this.jsonSchema,
-      output: () => this.jsonSchema,
-    },
-    validate: (value: unknown) => {
-      if (!(value instanceof this.classConstructor)) {
-        return {
-          issues: [
-            {
-              message: `Expected instance of ${this.classConstructor.name}`,
-            },
-          ],
-        };
-      }
+function keyGroupJsonSchema(keys: string[], rule: KeyGroupRule): Record<string, unknown> {
+  const required = keys.map((key) => ({ required: [key] }));
+  const pairs = keys.flatMap((a, i) => keys.slice(i + 1).map((b) => ({ required: [a, b] })));
+  return rule === "exactlyOne"
+    ? { oneOf: required }
+    : rule === "atLeastOne"
+      ? { anyOf: required }
+      : { not: { anyOf: pairs } };
+}
 
-      const result = this.innerSchema["~standard"].validate(value);
-      return result as StandardSchemaV1.Result<O>;
-    },
-    types: {
-      input: {} as I,
-      output: {} as O,
-    },
-  };
+function toOptional(schema: BaseSchema<any, any>): BaseSchema<any, any> {
+  return schema instanceof OptionalSchema || schema instanceof DefaultSchema
+    ? schema
+    : schema.optional();
 }
 
-export class ObjectSchemaType<T extends Record<string, unknown>> extends BaseSchema<unknown, T> {
-  readonly definition: SchemaDefinition;
+/** Mapped schemas by source schema, shared by one `deepPartial()` or `deepStrict()` call */
+type SchemaMemo = WeakMap<BaseSchema<any, any>, BaseSchema<any, any>>;
 
-  constructor(definition: SchemaDefinition) {
-    super();
-    this.definition = definition;
+/**
+ * Rebuild a property schema with `map` applied to the objects it holds, looking through
+ * wrappers such as `optional()` or `default()` and containers such as unions, tuples and maps.
+ * Schemas already mapped are taken from `memo`, so recursion through `h.lazy()` comes back to
+ * one lazy schema and its depth limit still applies.
+ */
+function mapNestedObjects(
+  schema: BaseSchema<any, any>,
+  map: (schema: ObjectSchemaType<any>) => BaseSchema<any, any>,
+  memo: SchemaMemo,
+): BaseSchema<any, any> {
+  const known = memo.get(schema);
+  if (known) {
+    return known;
+  }
+  if (schema instanceof ObjectSchemaType) {
+    return map(schema);
+  }
+  const s = schema as any;
+  const nested = (child: Schema<any, any>) =>
+    mapNestedObjects(child as BaseSchema<any, any>, map, memo);
+  if (schema instanceof LazySchema) {
+    const getter: () => Schema<any, any> = s.getter;
+    const lazy = reassemble(schema, new LazySchema(() => nested(getter())), [
+      "getter",
+      "resolved",
+      "depth",
+    ]);
+    memo.set(schema, lazy);
+    return lazy;
+  }
+  if (schema instanceof DiscriminatedUnionSchema) {
+    const discriminator: string = s.discriminator;
+    const options = (s.schemas as ObjectSchemaType<any>[]).map((option) => {
+      const mapped = nested(option);
+      // Keep the literal tag even when `map` makes properties optional
+      return mapped instanceof ObjectSchemaType
+        ? mapped.extend({ [discriminator]: option.definition[discriminator]! })
+        : mapped;
+    });
+    return reassemble(
+      schema,
+      new DiscriminatedUnionSchema(discriminator, options as ObjectSchemaType<any>[]),
+      ["schemas", "options"],
+    );
+  }
+  const container = containerOf(schema);
+  if (!container) {
+    return schema;
+  }
+  const mapped = container.children.map(nested);
+  if (mapped.every((child, index) => child === container.children[index])) {
+    return schema;
+  }
+  return reassemble(schema, container.rebuild(mapped), container.keys);
+}
 
-    const properties: Record<string, any> = {};
-    const required: string[] = [];
+interface Container {
+  children: Schema<any, any>[];
+  rebuild: (children: BaseSchema<any, any>[]) => BaseSchema<any, any>;
+  /** Fields computed from the children, taken from the rebuilt schema */
+  keys: string[];
+}
 
-    for (const key in definition) {
-      const schemaItem = definition[key];
-      const isOptional = schemaItem instanceof OptionalSchema;
+function containerOf(schema: BaseSchema<any, any>): Container | undefined {
+  const s = schema as any;
+  const wrap = (create: (inner: BaseSchema<any, any>) => BaseSchema<any, any>): Container => ({
+    children: [s.innerSchema],
+    rebuild: ([inner]) => create(inner!),
+    keys: ["innerSchema"],
+  });
+  if (schema instanceof OptionalSchema) {
+    return wrap((inner) => new OptionalSchema(inner));
+  }
+  if (schema instanceof NullableSchema) {
+    return wrap((inner) => new NullableSchema(inner));
+  }
+  if (schema instanceof DefaultSchema) {
+    return wrap((inner) => new DefaultSchema(inner, s.defaultValue));
+  }
+  if (schema instanceof CatchSchema) {
+    return wrap((inner) => new CatchSchema(inner, s.fallback));
+  }
+  if (schema instanceof BrandedSchema) {
+    return wrap((inner) => new BrandedSchema(inner, s.brandName));
+  }
+  if (schema instanceof TransformSchema) {
+    return wrap((inner) => new TransformSchema(inner, s.fn));
+  }
+  if (schema instanceof InstanceOfSchema) {
+    return wrap((inner) => new InstanceOfSchema(inner, s.classConstructor));
+  }
+  if (schema instanceof PromiseSchema) {
+    return wrap((inner) => new PromiseSchema(inner));
+  }
+  if (schema instanceof ArraySchema) {
+    const overrides: Map<number, Schema<any, any>> = s._overrides;
+    return {
+      children: [s.innerSchema, ...overrides.values()],
+      rebuild: ([inner, ...mapped]) => {
+        let array = new ArraySchema<any, any[]>(inner!);
+        for (const [i, index] of [...overrides.keys()].entries()) {
+          array = array.overrideAt(index, mapped[i]!);
+        }
+        return array;
+      },
+      keys: ["innerSchema", "_overrides"],
+    };
+  }
+  if (schema instanceof SetSchema) {
+    return wrap((inner) => new SetSchema(inner));
+  }
+  if (schema instanceof PipeSchema) {
+    return {
+      children: [s.innerSchema, s.nextSchema],
+      rebuild: ([inner, next]) => new PipeSchema(inner!, next!),
+      keys: ["innerSchema", "nextSchema"],
+    };
+  }
+  if (schema instanceof IntersectionSchema) {
+    return {
+      children: [s.left, s.right],
+      rebuild: ([left, right]) => new IntersectionSchema(left!, right!),
+      keys: ["left", "right"],
+    };
+  }
+  if (schema instanceof MapSchema) {
+    return {
+      children: [s.keySchema, s.valueSchema],
+      rebuild: ([key, value]) => new MapSchema(key!, value!),
+      keys: ["keySchema", "valueSchema"],
+    };
+  }
+  if (schema instanceof TupleSchema) {
+    const items: Schema<any, any>[] = s.items;
+    const rest: Schema<any, any> | undefined = s.restSchema;
+    return {
+      children: rest ? [...items, rest] : items,
+      rebuild: (children) =>
+        new TupleSchema(children.slice(0, items.length), rest && children[items.length]),
+      keys: ["items", "restSchema"],
+    };
+  }
+  if (schema instanceof UnionSchema && !(schema instanceof LiteralsSchema)) {
+    return {
+      children: s.schemas,
+      rebuild: (children) => new UnionSchema(...children),
+      keys: ["schemas", "probes"],
+    };
+  }
+  return undefined;
+}
 
-      if (!isOptional) {
-        required.push(key);
-      }
+/**
+ * Copy the configuration of `schema` onto `rebuilt`, a fresh schema built around mapped
+ * children, keeping the fields derived from those children and their JSON Schema
+ */
+function reassemble<S extends BaseSchema<any, any>>(schema: S, rebuilt: S, keys: string[]): S {
+  const derived: Record<string, unknown> = {};
+  for (const key of keys) {
+    derived[key] = (rebuilt as any)[key];
+  }
+  const jsonSchema = rebuilt.jsonSchema;
+  Object.assign(rebuilt, schema, derived);
+  rebuilt.jsonSchema = { ...schema.jsonSchema, ...jsonSchema };
+  rebuilt.schema = rebuilt;
+  return rebuilt;
+}
 
-      if (typeof schemaItem === "string") {
-        properties[key] = { type: schemaItem };
-      } else if (schemaItem instanceof BaseSchema) {
-        properties[key] = schemaItem.jsonSchema;
-      } else if (typeof schemaItem === "object" && schemaItem !== null) {
-        properties[key] = { type: "object", properties: {} };
-      }
-    }
+export type AnySchema = SchemaPrimitive | BaseSchema<any, any> | SchemaDefinition;
 
-    this.jsonSchema = {
-      type: "object",
-      properties,
-      required: required.length > 0 ? required : undefined,
-    };
-  }
+const SCHEMA_ID = Symbol("schemaId");
 
-  readonly "~standard": CombinedStandardProps<unknown, T> = {
-    version: 1,
-    vendor: "h-schema",
-    jsonSchema: {
-      input: () => this.jsonSchema,
-      output: () => this.jsonSchema,
-    },
-    validate: (value: unknown): StandardSchemaV1.Result<T> => {
-      if (typeof value !== "object" || value === null || Array.isArray(value)) {
-        return {
-          issues: [
-            {
-              message:
-                "Expected object, received " +
-                (value === null ? "null" : Array.isArray(value) ? "array" : typeof value),
-            },
-          ],
-        };
-      }
+interface SchemaId {
+  name: string;
+  definition: Record<string, unknown>;
+}
 
-      const obj = value as Record<string, unknown>;
-      const result: Record<string, unknown> = {};
-      const issues: StandardSchemaV1.Issue[] = [];
+/**
+ * Replace every JSON Schema tagged by `id()` with a `$ref`, collecting the definitions.
+ * Keys added on top of a named schema (e.g. `default`) are kept next to the `$ref`.
+ */
+function collectDefinitions(
+  json: unknown,
+  definitions: Record<string, unknown>,
+  prefix: string,
+): unknown {
+  if (Array.isArray(json)) {
+    return json.map((item) => collectDefinitions(item, definitions, prefix));
+  }
+  if (typeof json !== "object" || json === null) {
+    return json;
+  }
+  const id = (json as { [SCHEMA_ID]?: SchemaId })[SCHEMA_ID];
+  const result: Record<string, unknown> = id ? { $ref: `${prefix}${id.name}` } : {};
+  if (id && !(id.name in definitions)) {
+    definitions[id.name] = {};
+    definitions[id.name] = collectDefinitions(id.definition, definitions, prefix);
+  }
+  for (const [key, value] of Object.entries(json)) {
+    if (!id || id.definition[key] !== value) {
+      result[key] = collectDefinitions(value, definitions, prefix);
+    }
+  }
+  return result;
+}
 
-      for (const key in this.definition) {
-        const schemaItem = this.definition[key];
-        const isOptional = schemaItem instanceof OptionalSchema;
+/** JSON Schema draft emitted by `toJsonSchema()` */
+export type JsonSchemaDraft = "draft-2020-12" | "draft-07";
 
-        if (!(key in obj) && !isOptional) {
-          issues.push({
-            message: `Missing required property: ${key}`,
-            path: [key],
-          });
-          continue;
-        }
+const JSON_SCHEMA_URIS: Record<JsonSchemaDraft, string> = {
+  "draft-2020-12": "https://json-schema.org/draft/2020-12/schema",
+  "draft-07": "http://json-schema.org/draft-07/schema#",
+};
 
-        if (key in obj) {
-          if (typeof schemaItem === "string" && schemaItem in ["string", "number", "boolean"]) {
-            const schemaPrimitive = schemaItem as SchemaPrimitive;
-            if (!validatePrimitive(schemaPrimitive, obj[key])) {
-              issues.push({
-                message: `Invalid type for property ${key}: expected ${schemaPrimitive}`,
-                path: [key],
-              });
-            } else {
-              result[key] = obj[key];
-            }
-          } else if (schemaItem instanceof BaseSchema) {
-            const validationResult = schemaItem["~standard"].validate(
-              obj[key],
-            ) as StandardSchemaV1.Result<any>;
-            if ("issues" in validationResult) {
-              if (validationResult.issues) {
-                issues.push(
-                  ...validationResult.issues.map((issue) => ({
-                    ...issue,
-                    path: issue.path ? [key, ...issue.path] : [key],
-                  })),
-                );
-              }
-            } else {
-              result[key] = validationResult.value;
-            }
-          }
-        }
-      }
+/** Keywords holding instance data rather than subschemas */
+const DATA_KEYWORDS = new Set(["const", "default", "enum", "examples"]);
+/** Keywords holding a map of names to subschemas */
+const SCHEMA_MAPS = new Set(["properties", "patternProperties", "$defs", "definitions"]);
 
-      if (issues.length > 0) {
-        return { issues };
-      }
+/** Rewrite keywords draft-07 lacks: `prefixItems` becomes the `items` tuple form */
+function toDraft07(json: unknown): unknown {
+  if (Array.isArray(json)) {
+    return json.map(toDraft07);
+  }
+  if (!isPlainJson(json)) {
+    return json;
+  }
+  const result: Record<string, unknown> = {};
+  for (const [key, value] of Object.entries(json)) {
+    if (DATA_KEYWORDS.has(key)) {
+      result[key] = value;
+    } else if (SCHEMA_MAPS.has(key) && isPlainJson(value)) {
+      const entries = Object.entries(value).map(([name, schema]) => [name, toDraft07(schema)]);
+      result[key] = Object.fromEntries(entries);
+    } else {
+      result[key] = toDraft07(value);
+    }
+  }
+  if (!("prefixItems" in result)) {
+    return result;
+  }
+  const { prefixItems, items, ...rest } = result;
+  return items === undefined
+    ? { ...rest, items: prefixItems }
+    : { ...rest, items: prefixItems, additionalItems: items };
+}
 
-      return { value: result as T };
-    },
-    types: {
-      input: {} as unknown,
-      output: {} as T,
-    },
-  };
+function buildJsonSchema(schema: AnySchema, target: string): Record<string, unknown> {
+  const std = toStandard(schema);
+  const json = std["~standard"].jsonSchema.output({
+    target: target as StandardJSONSchemaV1.Target,
+  });
+  const key = target === "draft-07" ? "definitions" : "$defs";
+  const definitions: Record<string, unknown> = {};
+  const result = collectDefinitions(json, definitions, `#/${key}/`) as Record<string, unknown>;
+  const output = Object.keys(definitions).length > 0 ? { ...result, [key]: definitions } : json;
+  return target === "draft-07" ? (toDraft07(output) as Record<string, unknown>) : output;
 }
 
-export type AnySchema = SchemaPrimitive | BaseSchema<any, any> | SchemaDefinition;
+/** @deprecated Calling `h.nullable()` without a schema creates a null schema; use `h.null()` */
+function nullable(): NullSchemaType;
+function nullable<S extends AnySchema>(schema: S): NullableSchema<unknown, InferSchema<S>>;
+function nullable(schema?: AnySchema): NullSchemaType | NullableSchema<unknown, unknown> {
+  if (schema === undefined) {
+    return new NullSchemaType();
+  }
+  return new NullableSchema(toStandard(schema).schema);
+}
 
 function toStandard<T>(schema: AnySchema): Schema<unknown, T> {
   let standardSchema: Schema<unknown, T>;
@@ -845,6 +4380,8 @@ function toStandard<T>(schema: AnySchema): Schema<unknown, T> {
     enum: standardSchema.enum.bind(standardSchema),
     array: standardSchema.array.bind(standardSchema),
     instanceOf: standardSchema.instanceOf.bind(standardSchema),
+    getType: standardSchema.getType.bind(standardSchema),
+    isOptional: standardSchema.isOptional.bind(standardSchema),
   };
 }
 
@@ -863,6 +4400,11 @@ export const h = {
    * @returns {NumberSchemaType} Number schema type
    */
   number: (): NumberSchemaType => new NumberSchemaType(),
+  /**
+   * Create bigint schema type
+   * @returns {BigIntSchemaType} BigInt schema type
+   */
+  bigint: (): BigIntSchemaType => new BigIntSchemaType(),
   /**
    * Create boolean schema type
    * @returns {BooleanSchemaType} Boolean schema type
@@ -874,10 +4416,11 @@ export const h = {
    */
   null: (): NullSchemaType => new NullSchemaType(),
   /**
-   * Create null schema type (alias for {@link h.null})
-   * @returns {NullSchemaType} Null schema type
+   * Create nullable schema type accepting the schema's values or null
+   * @param {S} schema - Schema
+   * @returns {NullableSchema<unknown, InferSchema<S>>} Nullable schema type
    */
-  nullable: (): NullSchemaType => new NullSchemaType(),
+  nullable,
 
   /**
    * Create any schema type
@@ -885,6 +4428,28 @@ export const h = {
    */
   any: (): AnySchemaType => new AnySchemaType(),
 
+  /**
+   * Create never schema type, rejecting every value
+   * @returns {NeverSchemaType} Never schema type
+   */
+  never: (): NeverSchemaType => new NeverSchemaType(),
+
+  /**
+   * Create void schema type, accepting only `undefined`
+   * @returns {VoidSchemaType} Void schema type
+   */
+  void: (): VoidSchemaType => new VoidSchemaType(),
+
+  /**
+   * Create function schema type, e.g. for plugin callbacks
+   * @param {number} [minArgs] - Minimum number of declared parameters
+   * @returns {FunctionSchemaType} Function schema type
+   */
+  function: (minArgs?: number): FunctionSchemaType => {
+    const schema = new FunctionSchemaType();
+    return minArgs === undefined ? schema : schema.minArgs(minArgs);
+  },
+
   /**
    * Create literal schema type
    * @param {T} value - Literal value
@@ -912,6 +4477,44 @@ export const h = {
     return base.array() as ArraySchema<unknown, SchemaType<S>[]>;
   },
 
+  /**
+   * Create set schema type validating each element of a `Set`
+   * @param {S} schema - Schema for the elements
+   * @returns {SetSchema<unknown, SchemaType<S>>} Set schema type
+   */
+  set: <S extends AnySchema>(schema: S): SetSchema<unknown, SchemaType<S>> => {
+    return new SetSchema<unknown, SchemaType<S>>(toStandard<SchemaType<S>>(schema).schema);
+  },
+
+  /**
+   * Create map schema type validating each key and value of a `Map`
+   * @param {K} key - Schema for the keys
+   * @param {V} value - Schema for the values
+   * @returns {MapSchema<unknown, SchemaType<K>, SchemaType<V>>} Map schema type
+   */
+  map: <K extends AnySchema, V extends AnySchema>(
+    key: K,
+    value: V,
+  ): MapSchema<unknown, SchemaType<K>, SchemaType<V>> => {
+    return new MapSchema<unknown, SchemaType<K>, SchemaType<V>>(
+      toStandard<SchemaType<K>>(key).schema,
+      toStandard<SchemaType<V>>(value).schema,
+    );
+  },
+
+  /**
+   * Create tuple schema type with fixed positional types
+   * @param {S} items - Schema for each position
+   * @returns {TupleSchema<unknown, { [K in keyof S]: SchemaType<S[K]> }>} Tuple schema type
+   */
+  tuple: <S extends [AnySchema, ...AnySchema[]]>(
+    items: S,
+  ): TupleSchema<unknown, { [K in keyof S]: SchemaType<S[K]> }> => {
+    return new TupleSchema<unknown, { [K in keyof S]: SchemaType<S[K]> }>(
+      items.map((item) => toStandard(item).schema),
+    );
+  },
+
   /**
    * Create enum schema type from a list of string, number or boolean values.
    * @param {Values} values - An array of literal values.
@@ -927,6 +4530,20 @@ export const h = {
     return h.options(...literalSchemas) as UnionSchema<unknown, Values[number]>;
   },
 
+  /**
+   * Create a union of literal values with a single combined issue
+   * @param {Values} values - Allowed literal values
+   * @returns {LiteralsSchema<unknown, Values[number]>} Schema matching one of the values
+   */
+  literals: <T extends string | number | boolean, Values extends readonly [T, ...T[]]>(
+    values: Values,
+  ): LiteralsSchema<unknown, Values[number]> => {
+    if (!values || values.length === 0) {
+      throw new Error("h.literals() requires a non-empty array of values.");
+    }
+    return new LiteralsSchema<unknown, Values[number]>(values);
+  },
+
   /**
    * Create optional schema type
    * @param {S} schema - Schema
@@ -948,6 +4565,51 @@ export const h = {
     return new UnionSchema<unknown, InferSchema<S[number]>>(...stdSchemas);
   },
 
+  /**
+   * Create intersection schema type
+   * @param {A} left - First schema
+   * @param {B} right - Second schema, wins on conflicting object keys
+   * @returns {IntersectionSchema<unknown, SchemaType<A> & SchemaType<B>>} Intersection schema type
+   */
+  intersection: <A extends AnySchema, B extends AnySchema>(
+    left: A,
+    right: B,
+  ): IntersectionSchema<unknown, SchemaType<A> & SchemaType<B>> => {
+    return new IntersectionSchema<unknown, SchemaType<A> & SchemaType<B>>(
+      toStandard(left).schema,
+      toStandard(right).schema,
+    );
+  },
+
+  /**
+   * Create lazily resolved schema type, used for recursive schemas
+   * @param {() => Schema<unknown, T>} getter - Function returning the schema, called once
+   * @returns {LazySchema<unknown, T>} Lazy schema type
+   */
+  lazy: <T>(getter: () => Schema<unknown, T>): LazySchema<unknown, T> =>
+    new LazySchema<unknown, T>(getter),
+
+  /**
+   * Create promise schema type, validating the resolved value with `validateAsync()`
+   * @param {BaseSchema<unknown, T>} schema - Schema for the resolved value
+   * @returns {PromiseSchema<unknown, T>} Promise schema type
+   */
+  promise: <T>(schema: BaseSchema<unknown, T>): PromiseSchema<unknown, T> =>
+    new PromiseSchema<unknown, T>(schema),
+
+  /**
+   * Create discriminated union schema type
+   * @param {K} discriminator - Property whose literal value selects the option
+   * @param {S} schemas - Object schemas, each defining the discriminator as a literal
+   * @returns {DiscriminatedUnionSchema<unknown, S[number]["inferred"]>} Discriminated union schema type
+   */
+  discriminatedUnion: <K extends string, S extends ObjectSchemaType<any>[]>(
+    discriminator: K,
+    schemas: S,
+  ): DiscriminatedUnionSchema<unknown, S[number]["inferred"]> => {
+    return new DiscriminatedUnionSchema<unknown, S[number]["inferred"]>(discriminator, schemas);
+  },
+
   /**
    * Create instance of schema type
    * @param {C} constructor - Constructor function
@@ -966,6 +4628,12 @@ export const h = {
    */
   date: (): StringSchemaType => h.string().date(),
 
+  /**
+   * Create Date object schema type
+   * @returns {DateSchemaType} Schema validating `Date` instances
+   */
+  dateType: (): DateSchemaType => new DateSchemaType(),
+
   /**
    * Create UUID schema type
    * @returns {StringSchemaType} UUID schema type
@@ -997,6 +4665,139 @@ export const h = {
    */
   domain: (requireHttpOrHttps = true): StringSchemaType => h.string().domain(requireHttpOrHttps),
 
+  /**
+   * Create hex color schema type
+   * @param {boolean} allowAlpha - Also accept `#RGBA` and `#RRGGBBAA`
+   * @returns {StringSchemaType} Hex color schema type
+   */
+  hexColor: (allowAlpha = false): StringSchemaType => h.string().hexColor(allowAlpha),
+
+  /**
+   * Create semantic version schema type
+   * @returns {StringSchemaType} SemVer 2.0 schema type
+   */
+  semver: (): StringSchemaType => h.string().semver(),
+
+  /**
+   * Create emoji schema type
+   * @param {boolean} allowMultiple - Accept a sequence of emoji instead of exactly one
+   * @returns {StringSchemaType} Emoji schema type
+   */
+  emoji: (allowMultiple = false): StringSchemaType => h.string().emoji(allowMultiple),
+
+  /**
+   * Create URL slug schema type
+   * @returns {StringSchemaType} Slug schema type
+   */
+  slug: (): StringSchemaType => h.string().slug(),
+
+  /**
+   * Create hex-encoded string schema type
+   * @param {boolean} allowPrefix - Also accept a leading `0x`
+   * @returns {StringSchemaType} Hex schema type
+   */
+  hex: (allowPrefix = false): StringSchemaType => h.string().hex(allowPrefix),
+
+  /**
+   * Create RFC 4648 base32 schema type
+   * @returns {StringSchemaType} Base32 schema type
+   */
+  base32: (): StringSchemaType => h.string().base32(),
+
+  /**
+   * Create RFC 2397 data URI schema type
+   * @param {string[]} mimePrefixes - Allowed MIME type prefixes, e.g. `["image/"]`
+   * @returns {StringSchemaType} Data URI schema type
+   */
+  dataUri: (mimePrefixes?: string[]): StringSchemaType => h.string().dataUri(mimePrefixes),
+
+  /**
+   * Create RFC 6838 MIME type schema type, e.g. `text/html; charset=utf-8`
+   * @returns {StringSchemaType} MIME type schema type
+   */
+  mimeType: (): StringSchemaType => h.string().mimeType(),
+
+  /**
+   * Create file name schema type whose extension must be in the list, ignoring case
+   * @param {string[]} allowed - Allowed extensions, with or without the leading dot
+   * @returns {StringSchemaType} File name schema type
+   */
+  fileExtension: (allowed: string[]): StringSchemaType => h.string().fileExtension(allowed),
+
+  /**
+   * Create ISO 8601 date-time schema type, requiring `Z` or a UTC offset
+   * @param {boolean} normalize - Output the value converted to UTC, e.g. `2023-01-01T10:00:00Z`
+   * @returns {StringSchemaType} Date-time schema type
+   */
+  datetime: (normalize = false): StringSchemaType => h.string().datetime(normalize),
+
+  /**
+   * Create local date-time schema type, rejecting any UTC offset
+   * @returns {StringSchemaType} Local date-time schema type
+   */
+  datetimeLocal: (): StringSchemaType => h.string().datetimeLocal(),
+
+  /**
+   * Create MongoDB ObjectId schema type
+   * @returns {StringSchemaType} ObjectId schema type
+   */
+  objectId: (): StringSchemaType => h.string().objectId(),
+
+  /**
+   * Create ISBN schema type, verifying the check digit
+   * @param {IsbnVersion} version - Accept only ISBN-10 or ISBN-13; both by default
+   * @returns {StringSchemaType} ISBN schema type
+   */
+  isbn: (version?: IsbnVersion): StringSchemaType => h.string().isbn(version),
+
+  /**
+   * Create nanoid schema type
+   * @param {number} size - Expected number of characters
+   * @param {string} alphabet - Characters the ID may contain
+   * @returns {StringSchemaType} Nanoid schema type
+   */
+  nanoid: (size?: number, alphabet?: string): StringSchemaType => h.string().nanoid(size, alphabet),
+
+  /**
+   * Create MAC address schema type
+   * @param {MacSeparator[]} separators - Allowed separators
+   * @returns {StringSchemaType} MAC address schema type
+   */
+  macAddress: (separators?: MacSeparator[]): StringSchemaType => h.string().macAddress(separators),
+
+  /**
+   * Create RFC 1123 hostname schema type
+   * @returns {StringSchemaType} Hostname schema type
+   */
+  hostname: (): StringSchemaType => h.string().hostname(),
+
+  /**
+   * Create ISO 4217 currency code schema type
+   * @returns {StringSchemaType} Currency code schema type
+   */
+  currencyCode: (): StringSchemaType => h.string().currencyCode(),
+
+  /**
+   * Create ISO 3166-1 country code schema type
+   * @param {CountryCodeVariant} variant - `"alpha2"` (`US`) or `"alpha3"` (`USA`)
+   * @returns {StringSchemaType} Country code schema type
+   */
+  countryCode: (variant: CountryCodeVariant = "alpha2"): StringSchemaType =>
+    h.string().countryCode(variant),
+
+  /**
+   * Create postal code schema type, checked against the pattern of the given country
+   * @param {string} country - ISO 3166-1 alpha-2 code, e.g. `"US"`; unknown codes get a loose check
+   * @returns {StringSchemaType} Postal code schema type
+   */
+  postalCode: (country: string): StringSchemaType => h.string().postalCode(country),
+
+  /**
+   * Create IANA timezone schema type
+   * @returns {StringSchemaType} Timezone schema type
+   */
+  timezone: (): StringSchemaType => h.string().timezone(),
+
   /**
    * Convert schema to standard schema
    * @param {AnySchema} schema - Schema
@@ -1014,10 +4815,5 @@ export const h = {
   getJsonSchema: (
     schema: AnySchema,
     options: { target?: string } = {},
-  ): Record<string, unknown> => {
-    const std = toStandard(schema);
-    return std["~standard"].jsonSchema.output({
-      target: (options.target ?? "draft-2020-12") as StandardJSONSchemaV1.Target,
-    });
-  },
+  ): Record<string, unknown> => buildJsonSchema(schema, options.target ?? "draft-2020-12"),
 };
@@ -50,6 +50,29 @@ const app = new Framework()
       }),
     },
   )
+  .get(
+    "/reserved/:slug",
+    (context) => {
+      return {
+        slug: context.params.slug,
+        query: context.query,
+      };
+    },
+    {
+      params: h.object({
+        slug: h.string().refineAsync(async (slug) => slug !== "admin", "Slug is reserved"),
+      }),
+      query: h.object({
+        coupon: h.optional(
+          h.string().refineAsync(async (coupon) => coupon !== "expired", "Coupon has expired"),
+        ),
+      }),
+      response: h.object({
+        slug: h.string(),
+        query: h.object({ coupon: h.optional(h.string()) }),
+      }),
+    },
+  )
   .listen(3003);
 
 const client = createClient<typeof app>("http://localhost:3003");
@@ -115,6 +138,31 @@ describe("Test query parameters", () => {
     }
   });
 
+  it("should accept params and query passing async refinements", async () => {
+    const { data: response, status } = await client.reserved.slug("shop").get({
+      query: { coupon: "spring" },
+    });
+
+    expect(status).toBe(200);
+    expect(response).toEqual({ slug: "shop", query: { coupon: "spring" } });
+  });
+
+  it("should reject params failing an async refinement", async () => {
+    const { status, ok } = await client.reserved.slug("admin").get();
+
+    expect(status).toBe(400);
+    expect(ok).toBe(false);
+  });
+
+  it("should reject query parameters failing an async refinement", async () => {
+    const { status, ok } = await client.reserved.slug("shop").get({
+      query: { coupon: "expired" },
+    });
+
+    expect(status).toBe(400);
+    expect(ok).toBe(false);
+  });
+
   afterAll(() => {
     app.close();
   });
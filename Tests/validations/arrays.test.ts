@@ -0,0 +1,348 @@
+import { describe, expect, it } from "bun:test";
+import { h } from "@hedystia/validations";
+
+describe("Arrays", () => {
+  describe("length constraints", () => {
+    it("should reject an empty array when nonempty", () => {
+      expect(h.array(h.string()).nonempty()["~standard"].validate([])).toEqual({
+        issues: [{ message: "Array must contain at least 1 element(s)", code: "too_small" }],
+      });
+    });
+
+    it("should enforce min and max", () => {
+      const schema = h.array(h.number()).min(1).max(2);
+      expect(schema["~standard"].validate([1, 2])).toEqual({ value: [1, 2] });
+      expect(schema["~standard"].validate([1, 2, 3])).toEqual({
+        issues: [{ message: "Array must contain at most 2 element(s)", code: "too_big" }],
+      });
+    });
+
+    it("should enforce an exact length", () => {
+      const schema = h.array(h.number()).length(2);
+      expect("issues" in schema["~standard"].validate([1])).toBe(true);
+      expect("issues" in schema["~standard"].validate([1, 2, 3])).toBe(true);
+      expect(schema.jsonSchema).toEqual({
+        type: "array",
+        items: { type: "number" },
+        minItems: 2,
+        maxItems: 2,
+      });
+    });
+  });
+
+  describe("issue paths", () => {
+    it("should keep array indices as numbers and object keys as strings", () => {
+      const schema = h.object({ items: h.array(h.object({ "0": h.string() })) });
+      const result = schema["~standard"].validate({ items: [{ "0": 1 }] });
+      const path = "issues" in result ? result.issues[0]?.path : undefined;
+      expect(path).toEqual(["items", 0, "0"]);
+      expect(path?.map((segment) => typeof segment)).toEqual(["string", "number", "string"]);
+    });
+  });
+
+  describe("element()", () => {
+    it("should validate a single item with the array's item rules", () => {
+      const schema = h.array(h.string().minLength(2));
+      expect(schema.element()["~standard"].validate("ab")).toEqual({ value: "ab" });
+      expect("issues" in schema.element()["~standard"].validate("a")).toBe(true);
+    });
+  });
+
+  describe("unique()", () => {
+    it("should reject duplicate primitives at the duplicate's index", () => {
+      const schema = h.array(h.number()).unique();
+      expect(schema["~standard"].validate([1, 2, 3])).toEqual({ value: [1, 2, 3] });
+      expect(schema["~standard"].validate([1, 2, 2])).toEqual({
+        issues: [{ message: "Array must contain unique elements", path: [2], code: "not_unique" }],
+      });
+    });
+
+    it("should compare objects structurally", () => {
+      const schema = h.array(h.object({ id: h.number() })).unique();
+      expect("issues" in schema["~standard"].validate([{ id: 1 }, { id: 1 }])).toBe(true);
+      expect("value" in schema["~standard"].validate([{ id: 1 }, { id: 2 }])).toBe(true);
+    });
+
+    it("should ignore key order and keep distinct primitives apart", () => {
+      const objects = h.array(h.object({ a: h.number(), b: h.number() })).unique();
+      const reordered = objects.validateWithOptions([
+        { a: 1, b: 2 },
+        { b: 2, a: 1 },
+      ]);
+      expect(reordered.issues?.[0]?.path).toEqual([1]);
+      const values = h.array(h.any()).unique();
+      expect(values.validateWithOptions([undefined, null, Number.NaN])).toEqual({
+        value: [undefined, null, Number.NaN],
+      });
+      expect(values.validateWithOptions([Number.NaN, Number.NaN]).issues?.[0]?.path).toEqual([1]);
+    });
+
+    it("should not throw on cyclic or bigint elements", () => {
+      const cyclic: Record<string, unknown> = {};
+      cyclic.self = cyclic;
+      const values = h.array(h.any()).unique();
+      expect(values.validateWithOptions([{ n: 1n }, { n: 2n }])).toEqual({
+        value: [{ n: 1n }, { n: 2n }],
+      });
+      expect(values.validateWithOptions([cyclic, { self: cyclic }]).issues?.[0]?.path).toEqual([1]);
+    });
+
+    it("should compare the result of a key function", () => {
+      const schema = h.array(h.object({ id: h.number(), name: h.string() })).unique((u) => u.id);
+      expect(
+        schema.validateWithOptions([
+          { id: 1, name: "a" },
+          { id: 1, name: "b" },
+        ]).issues?.[0]?.path,
+      ).toEqual([1]);
+    });
+
+    it("should emit uniqueItems", () => {
+      expect(h.array(h.string()).unique().jsonSchema.uniqueItems).toBe(true);
+    });
+  });
+
+  describe("ascending() / descending()", () => {
+    it("should report the first out-of-order index", () => {
+      const schema = h.array(h.number()).ascending();
+      expect(schema["~standard"].validate([1, 2, 2, 3])).toEqual({ value: [1, 2, 2, 3] });
+      expect(schema["~standard"].validate([1, 3, 2])).toEqual({
+        issues: [
+          { message: "Array must be sorted in ascending order", path: [2], code: "not_sorted" },
+        ],
+      });
+    });
+
+    it("should compare strings and support descending order", () => {
+      const schema = h.array(h.string()).descending();
+      expect(schema["~standard"].validate(["c", "b", "a"])).toEqual({ value: ["c", "b", "a"] });
+      expect(schema.validateWithOptions(["c", "a", "b"]).issues?.[0]?.path).toEqual([2]);
+    });
+
+    it("should accept a custom comparator", () => {
+      const schema = h.array(h.object({ id: h.number() })).ascending((a, b) => a.id - b.id);
+      expect("value" in schema["~standard"].validate([{ id: 1 }, { id: 5 }])).toBe(true);
+      expect(schema.validateWithOptions([{ id: 5 }, { id: 1 }]).issues?.[0]?.path).toEqual([1]);
+      expect(schema.jsonSchema["x-sorted"]).toBe("ascending");
+    });
+  });
+
+  describe("coerce()", () => {
+    const tags = h.array(h.string()).coerce();
+
+    it("should wrap a single value in an array", () => {
+      expect(tags["~standard"].validate("a")).toEqual({ value: ["a"] });
+      expect(tags["~standard"].validate(["a", "b"])).toEqual({ value: ["a", "b"] });
+    });
+
+    it("should validate the wrapped value as an item", () => {
+      expect(tags["~standard"].validate(1)).toEqual({
+        issues: [
+          {
+            message: "Expected string, received number",
+            path: [0],
+            code: "invalid_type",
+            expected: "string",
+            received: "number",
+          },
+        ],
+      });
+      expect("issues" in tags["~standard"].validate(undefined)).toBe(true);
+    });
+  });
+
+  describe("overrideAt()", () => {
+    const row = h.array(h.string()).overrideAt(0, h.number());
+
+    it("should validate the overridden index against its own schema", () => {
+      expect(row["~standard"].validate([1, "a", "b"])).toEqual({ value: [1, "a", "b"] });
+      expect(row["~standard"].validate(["1", "a"])).toEqual({
+        issues: [
+          {
+            message: "Expected number, received string",
+            path: [0],
+            code: "invalid_type",
+            expected: "number",
+            received: "string",
+          },
+        ],
+      });
+      expect("issues" in row["~standard"].validate([1, 2])).toBe(true);
+    });
+
+    it("should not affect the base schema", () => {
+      const base = h.array(h.string());
+      base.overrideAt(0, h.number());
+      expect("issues" in base["~standard"].validate([1])).toBe(true);
+    });
+
+    it("should emit prefixItems up to the last override", () => {
+      expect(h.array(h.string()).overrideAt(1, h.number()).jsonSchema).toEqual({
+        type: "array",
+        items: { type: "string" },
+        prefixItems: [{ type: "string" }, { type: "number" }],
+      });
+    });
+  });
+
+  describe("h.tuple()", () => {
+    const schema = h.tuple([h.string(), h.number()]);
+
+    it("should validate each position", () => {
+      expect(schema["~standard"].validate(["a", 1])).toEqual({ value: ["a", 1] });
+      expect(schema["~standard"].validate([1, 1])).toEqual({
+        issues: [
+          {
+            message: "Expected string, received number",
+            path: [0],
+            code: "invalid_type",
+            expected: "string",
+            received: "number",
+          },
+        ],
+      });
+    });
+
+    it("should require the exact length without a rest schema", () => {
+      expect(schema["~standard"].validate(["a"])).toEqual({
+        issues: [{ message: "Expected tuple of 2 element(s), received 1", code: "too_small" }],
+      });
+      expect("issues" in schema["~standard"].validate(["a", 1, 2])).toBe(true);
+    });
+
+    it("should validate extra elements against the rest schema", () => {
+      const withRest = schema.rest(h.boolean());
+      expect(withRest["~standard"].validate(["a", 1, true, false])).toEqual({
+        value: ["a", 1, true, false],
+      });
+      expect(withRest["~standard"].validate(["a", 1, "x"])).toEqual({
+        issues: [
+          {
+            message: "Expected boolean, received string",
+            path: [2],
+            code: "invalid_type",
+            expected: "boolean",
+            received: "string",
+          },
+        ],
+      });
+    });
+
+    it("should emit prefixItems", () => {
+      expect(schema.jsonSchema).toEqual({
+        type: "array",
+        prefixItems: [{ type: "string" }, { type: "number" }],
+        items: false,
+        minItems: 2,
+        maxItems: 2,
+      });
+      expect(schema.rest(h.boolean()).jsonSchema).toEqual({
+        type: "array",
+        prefixItems: [{ type: "string" }, { type: "number" }],
+        items: { type: "boolean" },
+        minItems: 2,
+      });
+    });
+
+    it("should keep the tuple configuration when adding a rest schema", () => {
+      const described = schema
+        .refine((value) => value[1] > 0, "Second element must be positive")
+        .describe("Pair");
+      const withRest = described.rest(h.boolean());
+      expect(withRest.jsonSchema.description).toBe("Pair");
+      expect(withRest.validateWithOptions(["a", 0, true]).issues?.[0]?.message).toBe(
+        "Second element must be positive",
+      );
+    });
+  });
+
+  describe("h.set()", () => {
+    it("should validate each element and return a new Set", () => {
+      const input = new Set(["a", "b"]);
+      const result = h.set(h.string())["~standard"].validate(input);
+      expect(result).toEqual({ value: new Set(["a", "b"]) });
+      expect("value" in result && result.value !== input).toBe(true);
+    });
+
+    it("should report the offending element's position", () => {
+      expect(h.set(h.string())["~standard"].validate(new Set(["a", 1]))).toEqual({
+        issues: [
+          {
+            message: "Expected string, received number",
+            path: [1],
+            code: "invalid_type",
+            expected: "string",
+            received: "number",
+          },
+        ],
+      });
+    });
+
+    it("should reject non-Set values and enforce size bounds", () => {
+      expect("issues" in h.set(h.string())["~standard"].validate(["a"])).toBe(true);
+      const schema = h.set(h.number()).min(1).max(2);
+      expect("issues" in schema["~standard"].validate(new Set())).toBe(true);
+      expect("issues" in schema["~standard"].validate(new Set([1, 2, 3]))).toBe(true);
+    });
+
+    it("should emit an array with uniqueItems", () => {
+      expect(h.set(h.string()).jsonSchema).toEqual({
+        type: "array",
+        items: { type: "string" },
+        uniqueItems: true,
+      });
+    });
+  });
+
+  describe("h.map()", () => {
+    const schema = h.map(h.string(), h.number());
+
+    it("should validate entries and return a new Map", () => {
+      expect(schema["~standard"].validate(new Map([["a", 1]]))).toEqual({
+        value: new Map([["a", 1]]),
+      });
+    });
+
+    it("should report whether the key or the value failed", () => {
+      const input = new Map<unknown, unknown>([
+        ["a", 1],
+        [2, 2],
+        ["c", "3"],
+      ]);
+      expect(schema["~standard"].validate(input)).toEqual({
+        issues: [
+          {
+            message: "Expected string, received number",
+            path: [1, "key"],
+            code: "invalid_type",
+            expected: "string",
+            received: "number",
+          },
+          {
+            message: "Expected number, received string",
+            path: [2, "value"],
+            code: "invalid_type",
+            expected: "number",
+            received: "string",
+          },
+        ],
+      });
+    });
+
+    it("should reject non-Map values", () => {
+      expect("issues" in schema["~standard"].validate({ a: 1 })).toBe(true);
+    });
+
+    it("should only emit propertyNames for string keys", () => {
+      expect(schema.jsonSchema).toEqual({
+        type: "object",
+        propertyNames: { type: "string" },
+        additionalProperties: { type: "number" },
+      });
+      expect(h.map(h.number(), h.number()).jsonSchema).toEqual({
+        type: "object",
+        additionalProperties: { type: "number" },
+      });
+    });
+  });
+});
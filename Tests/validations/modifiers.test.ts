@@ -0,0 +1,329 @@
+import { describe, expect, it } from "bun:test";
+import { h } from "@hedystia/validations";
+
+describe("Schema modifiers", () => {
+  describe("optional() / nullable() / nullish()", () => {
+    const schema = h.object({
+      optional: h.string().optional(),
+      nullable: h.string().nullable(),
+      nullish: h.string().nullish(),
+    });
+
+    it("should accept a missing optional key but not null", () => {
+      expect(
+        "value" in schema["~standard"].validate({ nullable: null, nullish: undefined }),
+      ).toBe(true);
+      expect(
+        "issues" in schema["~standard"].validate({ optional: null, nullable: "a", nullish: "b" }),
+      ).toBe(true);
+    });
+
+    it("should require a nullable key to be present", () => {
+      const result = schema["~standard"].validate({ nullish: null });
+      expect("issues" in result && result.issues[0]?.path).toEqual(["nullable"]);
+    });
+
+    it("should accept null and undefined for nullish", () => {
+      const nullish = h.number().nullish();
+      expect(nullish["~standard"].validate(null)).toEqual({ value: null });
+      expect(nullish["~standard"].validate(undefined)).toEqual({ value: undefined });
+      expect("issues" in nullish["~standard"].validate("1")).toBe(true);
+    });
+
+    it("should emit a nullable JSON Schema type", () => {
+      expect(h.string().nullable().jsonSchema).toEqual({ type: ["string", "null"] });
+      expect(h.literal("a").nullable().jsonSchema).toEqual({
+        anyOf: [{ const: "a", type: "string" }, { type: "null" }],
+      });
+    });
+
+    it("should wrap a schema with h.nullable()", () => {
+      const schema = h.nullable(h.number());
+      expect(schema["~standard"].validate(null)).toEqual({ value: null });
+      expect(schema["~standard"].validate(1)).toEqual({ value: 1 });
+      expect("issues" in schema["~standard"].validate("1")).toBe(true);
+      expect(schema.jsonSchema).toEqual({ type: ["number", "null"] });
+    });
+
+    it("should keep h.nullable() without a schema as a null schema", () => {
+      expect(h.nullable()["~standard"].validate(null)).toEqual({ value: null });
+      expect("issues" in h.nullable()["~standard"].validate(1)).toBe(true);
+    });
+  });
+
+  describe("default()", () => {
+    it("should fill in undefined input", () => {
+      expect(h.number().default(0)["~standard"].validate(undefined)).toEqual({ value: 0 });
+      expect(h.number().default(0)["~standard"].validate(5)).toEqual({ value: 5 });
+    });
+
+    it("should still validate provided values", () => {
+      expect("issues" in h.number().default(0)["~standard"].validate("5")).toBe(true);
+    });
+
+    it("should make object keys optional on input but present on output", () => {
+      const schema = h.object({ page: h.number().default(1), name: h.string() });
+      expect(schema["~standard"].validate({ name: "a" })).toEqual({
+        value: { page: 1, name: "a" },
+      });
+      expect(schema.jsonSchema.required).toEqual(["name"]);
+      expect(schema.jsonSchema.properties.page).toEqual({ type: "number", default: 1 });
+    });
+  });
+
+  describe("catch()", () => {
+    it("should return the fallback on any failure", () => {
+      expect(h.number().catch(42)["~standard"].validate("not a number")).toEqual({ value: 42 });
+      expect(h.number().catch(42)["~standard"].validate(7)).toEqual({ value: 7 });
+    });
+
+    it("should work for nested object properties", () => {
+      const schema = h.object({ port: h.number().catch(3000), host: h.string() });
+      expect(schema["~standard"].validate({ port: "x", host: "localhost" })).toEqual({
+        value: { port: 3000, host: "localhost" },
+      });
+      expect("issues" in schema["~standard"].validate({ port: "x" })).toBe(true);
+    });
+  });
+
+  describe("refine()", () => {
+    const isEven = (n: number) => n % 2 === 0;
+
+    it("should add an issue when the predicate fails", () => {
+      const schema = h.number().refine(isEven, "must be even");
+      expect(schema["~standard"].validate(4)).toEqual({ value: 4 });
+      expect(schema["~standard"].validate(3)).toEqual({
+        issues: [{ message: "must be even", code: "custom" }],
+      });
+    });
+
+    it("should accumulate multiple refinements", () => {
+      const schema = h
+        .number()
+        .refine(isEven, "must be even")
+        .refine((n) => n > 10, "must be greater than 10");
+      const result = schema["~standard"].validate(3);
+      expect("issues" in result && result.issues.map((i) => i.message)).toEqual([
+        "must be even",
+        "must be greater than 10",
+      ]);
+    });
+
+    it("should keep builder methods available and not affect the base schema", () => {
+      const base = h.string();
+      const refined = base.refine((s) => s !== "admin", "reserved").minLength(2);
+      expect("issues" in refined["~standard"].validate("admin")).toBe(true);
+      expect("issues" in refined["~standard"].validate("a")).toBe(true);
+      expect(base["~standard"].validate("admin")).toEqual({ value: "admin" });
+    });
+
+    it("should report refinement issues with the property path inside objects", () => {
+      const schema = h.object({ n: h.number().refine(isEven, "must be even") });
+      expect(schema["~standard"].validate({ n: 1 })).toEqual({
+        issues: [{ message: "must be even", path: ["n"], code: "custom" }],
+      });
+    });
+  });
+
+  describe("superRefine()", () => {
+    const ports = h.array(h.number()).superRefine((values, context) => {
+      values.forEach((value, index) => {
+        if (value > 65535) {
+          context.addIssue({ message: "Port out of range", path: [index] });
+        }
+      });
+    });
+
+    it("should report every issue added by the callback", () => {
+      expect(ports["~standard"].validate([80, 70000, 443, 99999])).toEqual({
+        issues: [
+          { message: "Port out of range", path: [1], code: "custom" },
+          { message: "Port out of range", path: [3], code: "custom" },
+        ],
+      });
+      expect(ports["~standard"].validate([80, 443])).toEqual({ value: [80, 443] });
+    });
+
+    it("should prefix paths inside objects and keep custom codes", () => {
+      const bounds = h.object({ min: h.number(), max: h.number() });
+      const range = bounds.superRefine((value, context) => {
+        if (value.min > value.max) {
+          context.addIssue({ message: "min must not exceed max", path: ["min"], code: "too_big" });
+        }
+      });
+      expect(h.object({ range })["~standard"].validate({ range: { min: 5, max: 1 } })).toEqual({
+        issues: [{ message: "min must not exceed max", path: ["range", "min"], code: "too_big" }],
+      });
+    });
+
+    it("should stop at the first issue with abortEarly", () => {
+      expect(ports.validateWithOptions([70000, 70001], { abortEarly: true }).issues).toHaveLength(1);
+    });
+  });
+
+  describe("refineAsync()", () => {
+    const taken = new Set(["admin"]);
+    const isAvailable = async (name: string) => !taken.has(name);
+    const signup = h.object({
+      name: h.string().refineAsync(isAvailable, "Username is taken"),
+      tags: h.array(h.string().refineAsync(async (t) => t !== "x", "Invalid tag")),
+    });
+
+    it("should resolve to issues with their path when a check fails", async () => {
+      expect(await signup.validateAsync({ name: "admin", tags: ["a", "x"] })).toEqual({
+        issues: [
+          { message: "Username is taken", path: ["name"], code: "custom" },
+          { message: "Invalid tag", path: ["tags", 1], code: "custom" },
+        ],
+      });
+    });
+
+    it("should resolve to the value when every check passes", async () => {
+      expect(await signup.validateAsync({ name: "ada", tags: [] })).toEqual({
+        value: { name: "ada", tags: [] },
+      });
+    });
+
+    it("should skip async checks when synchronous validation fails", async () => {
+      let calls = 0;
+      const schema = h.number().refineAsync(async () => ++calls > 0, "never");
+      expect("issues" in (await schema.validateAsync("1"))).toBe(true);
+      expect(calls).toBe(0);
+    });
+
+    it("should return a promise from the Standard Schema validate", async () => {
+      const result = signup["~standard"].validate({ name: "admin", tags: [] });
+      expect(result).toBeInstanceOf(Promise);
+      expect(await result).toEqual({
+        issues: [{ message: "Username is taken", path: ["name"], code: "custom" }],
+      });
+    });
+
+    it("should return a plain Promise from the Standard Schema interface", async () => {
+      const result = signup["~standard"].validate({ name: "ada", tags: [] });
+      expect(result).toBeInstanceOf(Promise);
+      expect("issues" in result).toBe(false);
+      expect(await result).toEqual({ value: { name: "ada", tags: [] } });
+    });
+
+    it("should report an issue from the synchronous APIs", () => {
+      expect(signup.safeParse({ name: "ada", tags: [] })).toEqual({
+        success: false,
+        error: {
+          issues: [
+            {
+              message: "Async refinements require validateAsync()",
+              path: ["name"],
+              code: "custom",
+            },
+          ],
+        },
+      });
+    });
+  });
+
+  describe("brand()", () => {
+    it("should not change validation", () => {
+      const userId = h.string().uuid().brand("UserId");
+      const id = "123e4567-e89b-12d3-a456-426614174000";
+      expect(userId["~standard"].validate(id)).toEqual({ value: id });
+      expect("issues" in userId["~standard"].validate("nope")).toBe(true);
+    });
+
+    it("should record the brand in the JSON Schema", () => {
+      const orderId = h.string().brand("OrderId");
+      expect(orderId.brandName).toBe("OrderId");
+      expect(orderId.jsonSchema).toEqual({ type: "string", "x-brand": "OrderId" });
+    });
+  });
+
+  describe("preprocess()", () => {
+    const status = h.enum(["open", "closed"]).preprocess((s) => String(s).trim().toLowerCase());
+
+    it("should transform the input before validation", () => {
+      expect(status["~standard"].validate(" OPEN ")).toEqual({ value: "open" });
+      expect("issues" in status["~standard"].validate("pending")).toBe(true);
+    });
+
+    it("should run before optional handling", () => {
+      const schema = h
+        .number()
+        .optional()
+        .preprocess((v) => (v === "" ? undefined : v));
+      expect(schema["~standard"].validate("")).toEqual({ value: undefined });
+    });
+
+    it("should report a thrown error as an issue", () => {
+      const schema = h.string().preprocess(() => {
+        throw new Error("Cannot read input");
+      });
+      expect(schema["~standard"].validate("a")).toEqual({
+        issues: [{ message: "Cannot read input", code: "custom" }],
+      });
+    });
+  });
+
+  describe("transform()", () => {
+    it("should map the parsed value", () => {
+      const schema = h.string().transform((s) => s.length);
+      expect(schema["~standard"].validate("hello")).toEqual({ value: 5 });
+    });
+
+    it("should apply transforms in order", () => {
+      const schema = h
+        .string()
+        .transform((s) => s.trim())
+        .transform((s) => s.toUpperCase());
+      expect(schema["~standard"].validate("  ada ")).toEqual({ value: "ADA" });
+    });
+
+    it("should not run when validation or refinements fail", () => {
+      let calls = 0;
+      const schema = h
+        .number()
+        .refine((n) => n > 0, "must be positive")
+        .transform((n) => {
+          calls++;
+          return n * 2;
+        });
+      expect("issues" in schema["~standard"].validate(-1)).toBe(true);
+      expect("issues" in schema["~standard"].validate("1")).toBe(true);
+      expect(calls).toBe(0);
+    });
+
+    it("should leave the JSON Schema unaffected", () => {
+      expect(h.string().transform((s) => s.length).jsonSchema).toEqual({ type: "string" });
+    });
+  });
+
+  describe("pipe()", () => {
+    const schema = h
+      .string()
+      .transform((s) => Number.parseFloat(s))
+      .pipe(h.number().min(0));
+
+    it("should feed the output into the next schema", () => {
+      expect(schema["~standard"].validate("4.5")).toEqual({ value: 4.5 });
+    });
+
+    it("should report issues from the first stage", () => {
+      expect(schema["~standard"].validate(4)).toEqual({
+        issues: [
+          {
+            message: "Expected string, received number",
+            code: "invalid_type",
+            expected: "string",
+            received: "number",
+          },
+        ],
+      });
+    });
+
+    it("should report issues from the second stage with the correct path", () => {
+      const result = h.object({ amount: schema })["~standard"].validate({ amount: "-1" });
+      expect(result).toEqual({
+        issues: [{ message: "Number less than 0", path: ["amount"], code: "too_small" }],
+      });
+    });
+  });
+});
@@ -0,0 +1,770 @@
+import { describe, expect, it } from "bun:test";
+import { h, type ObjectSchemaType } from "@hedystia/validations";
+
+describe("Objects", () => {
+  describe("JSON Schema", () => {
+    it("should list exactly the non-optional keys as required", () => {
+      const schema = h.object({
+        id: h.number(),
+        name: h.string(),
+        nickname: h.string().optional(),
+        page: h.number().default(1),
+      });
+      expect(schema.jsonSchema.required).toEqual(["id", "name"]);
+    });
+
+    it("should describe nested plain definitions", () => {
+      const schema = h.object({ address: { street: "string", zip: "number" } });
+      expect(schema.jsonSchema.properties.address).toEqual({
+        type: "object",
+        properties: { street: { type: "string" }, zip: { type: "number" } },
+        required: ["street", "zip"],
+      });
+    });
+  });
+
+  describe("properties", () => {
+    it("should list every field under properties", () => {
+      const schema = h.object({ id: h.number(), name: h.string() });
+      expect(schema.jsonSchema.properties).toEqual({
+        id: { type: "number" },
+        name: { type: "string" },
+      });
+    });
+
+    it("should only treat own properties as provided", () => {
+      const schema = h.object({ toString: h.string() });
+      expect(schema.validateWithOptions({}).issues?.[0]?.message).toBe(
+        "Missing required property: toString",
+      );
+      expect(schema.validateWithOptions({ toString: "a" })).toEqual({ value: { toString: "a" } });
+    });
+
+    it("should list shorthand and nested definitions under properties", () => {
+      const schema = h.object({ name: "string", address: { zip: "number" } });
+      expect(schema.jsonSchema.properties).toEqual({
+        name: { type: "string" },
+        address: {
+          type: "object",
+          properties: { zip: { type: "number" } },
+          required: ["zip"],
+        },
+      });
+    });
+  });
+
+  describe("partial() / deepPartial()", () => {
+    const user = h.object({
+      name: h.string(),
+      address: h.object({ street: h.string(), zip: h.string() }),
+      tags: h.array(h.object({ label: h.string() })).max(2),
+    });
+
+    it("should make only top-level keys optional with partial()", () => {
+      const partial = user.partial();
+      expect(partial["~standard"].validate({})).toEqual({ value: {} });
+      expect("issues" in partial["~standard"].validate({ address: { street: "a" } })).toBe(true);
+      expect(partial.jsonSchema.required).toBeUndefined();
+    });
+
+    it("should make only the listed keys optional with partial(keys)", () => {
+      const account = h.object({ id: h.number(), email: h.string(), name: h.string() });
+      const update = account.partial(["email", "missing" as any]);
+      expect(update["~standard"].validate({ id: 1, name: "ada" })).toEqual({
+        value: { id: 1, name: "ada" },
+      });
+      expect("issues" in update["~standard"].validate({ name: "ada" })).toBe(true);
+      expect(update.jsonSchema.required).toEqual(["id", "name"]);
+    });
+
+    it("should keep the object configuration with partial()", () => {
+      const account = h
+        .object({ id: h.number(), name: h.string() })
+        .strict()
+        .refine((value) => value.id !== 0, "Id must not be zero")
+        .describe("Account");
+      const update = account.partial();
+      expect(update.validateWithOptions({ extra: 1 }).issues?.[0]?.message).toBe(
+        "Unrecognized key: extra",
+      );
+      expect(update.validateWithOptions({ id: 0 }).issues?.[0]?.message).toBe(
+        "Id must not be zero",
+      );
+      expect(update.jsonSchema.description).toBe("Account");
+      expect(update.jsonSchema.additionalProperties).toBe(false);
+    });
+
+    it("should make nested keys optional with deepPartial()", () => {
+      const patch = user.deepPartial();
+      expect(patch["~standard"].validate({ address: { street: "a" } })).toEqual({
+        value: { address: { street: "a" } },
+      });
+      expect(patch["~standard"].validate({ tags: [{}] })).toEqual({ value: { tags: [{}] } });
+      expect(patch.jsonSchema.properties.address.required).toBeUndefined();
+    });
+
+    it("should keep the object configuration with deepPartial()", () => {
+      const patch = user.strict().describe("User").deepPartial();
+      expect(patch.validateWithOptions({ extra: 1 }).issues?.[0]?.message).toBe(
+        "Unrecognized key: extra",
+      );
+      expect(patch.jsonSchema.description).toBe("User");
+    });
+
+    it("should keep property checks and array constraints", () => {
+      const patch = user.deepPartial();
+      expect("issues" in patch["~standard"].validate({ name: 1 })).toBe(true);
+      expect("issues" in patch["~standard"].validate({ tags: [{}, {}, {}] })).toBe(true);
+    });
+  });
+
+  describe("required()", () => {
+    const profile = h.object({
+      name: h.string(),
+      phone: h.string().optional(),
+      bio: h.string().optional(),
+    });
+
+    it("should make every optional property required", () => {
+      const schema = profile.required();
+      expect(schema.jsonSchema.required).toEqual(["name", "phone", "bio"]);
+      expect("issues" in schema["~standard"].validate({ name: "ada", phone: "1" })).toBe(true);
+    });
+
+    it("should make only the listed properties required", () => {
+      const verification = profile.required(["phone"]);
+      expect(verification.jsonSchema.required).toEqual(["name", "phone"]);
+      expect(verification["~standard"].validate({ name: "ada", phone: "123" })).toEqual({
+        value: { name: "ada", phone: "123" },
+      });
+      expect("issues" in verification["~standard"].validate({ name: "ada" })).toBe(true);
+    });
+
+    it("should compose with partial()", () => {
+      const schema = profile.partial().required(["name"]);
+      expect(schema.jsonSchema.required).toEqual(["name"]);
+    });
+
+    it("should keep key rules and refinements", () => {
+      const contact = profile
+        .atLeastOne(["phone", "bio"])
+        .refine((value) => value.name !== "root", "Reserved name")
+        .required(["phone"]);
+      expect(contact.validateWithOptions({ name: "root", phone: "1" }).issues?.[0]?.message).toBe(
+        "Reserved name",
+      );
+      expect(contact.jsonSchema.allOf).toHaveLength(1);
+    });
+  });
+
+  describe("pick() / omit()", () => {
+    const user = h.object({ id: h.number(), name: h.string(), email: h.string().email() });
+
+    it("should keep only the picked properties", () => {
+      const summary = user.pick(["id", "name"]);
+      expect(summary["~standard"].validate({ id: 1, name: "a" })).toEqual({
+        value: { id: 1, name: "a" },
+      });
+      expect(summary.jsonSchema.required).toEqual(["id", "name"]);
+      expect(Object.keys(summary.jsonSchema.properties)).toEqual(["id", "name"]);
+    });
+
+    it("should drop the omitted properties", () => {
+      const withoutEmail = user.omit(["email"]);
+      expect(withoutEmail["~standard"].validate({ id: 1, name: "a", email: "x" })).toEqual({
+        value: { id: 1, name: "a" },
+      });
+      expect("issues" in withoutEmail["~standard"].validate({ id: 1 })).toBe(true);
+      expect(withoutEmail.jsonSchema.required).toEqual(["id", "name"]);
+    });
+
+    it("should ignore keys inherited from Object.prototype", () => {
+      const summary = user.pick(["id", "toString" as any]);
+      expect(Object.keys(summary.definition)).toEqual(["id"]);
+      expect(summary.validateWithOptions({ id: 1 })).toEqual({ value: { id: 1 } });
+    });
+
+    it("should keep the object configuration", () => {
+      const strict = user.strict().describe("User");
+      for (const schema of [strict.pick(["id"]), strict.omit(["email", "name"])]) {
+        expect(schema.validateWithOptions({ id: 1, extra: 1 }).issues?.[0]?.message).toBe(
+          "Unrecognized key: extra",
+        );
+        expect(schema.jsonSchema.description).toBe("User");
+      }
+    });
+  });
+
+  describe("merge() / extend()", () => {
+    const a = h.object({ a: h.string() });
+    const b = h.object({ b: h.number() });
+
+    it("should require the properties of both schemas", () => {
+      const merged = a.merge(b);
+      expect(merged["~standard"].validate({ a: "x", b: 1 })).toEqual({ value: { a: "x", b: 1 } });
+      expect("issues" in merged["~standard"].validate({ a: "x" })).toBe(true);
+      expect(merged.jsonSchema.required).toEqual(["a", "b"]);
+    });
+
+    it("should let the other schema override conflicting keys", () => {
+      const merged = a.merge(h.object({ a: h.number().optional() }));
+      expect(merged["~standard"].validate({ a: 1 })).toEqual({ value: { a: 1 } });
+      expect(merged["~standard"].validate({})).toEqual({ value: {} });
+      expect(merged.jsonSchema.required).toBeUndefined();
+    });
+
+    it("should keep this schema's configuration and ignore the other's", () => {
+      const merged = a.strict().describe("A").merge(b.describe("B"));
+      expect(merged.validateWithOptions({ a: "x", b: 1, c: 1 }).issues?.[0]?.message).toBe(
+        "Unrecognized key: c",
+      );
+      expect(merged.jsonSchema.description).toBe("A");
+      expect(a.merge(b.strict()).validateWithOptions({ a: "x", b: 1, c: 1 })).toEqual({
+        value: { a: "x", b: 1 },
+      });
+    });
+
+    it("should add properties inline with extend()", () => {
+      const extended = a.extend({ c: h.boolean() });
+      expect(extended["~standard"].validate({ a: "x", c: true })).toEqual({
+        value: { a: "x", c: true },
+      });
+      expect(extended.jsonSchema.properties.c).toEqual({ type: "boolean" });
+    });
+
+    it("should keep the configuration with extend()", () => {
+      const extended = a.catchall(h.number()).extend({ c: h.boolean() });
+      const result = extended.validateWithOptions({ a: "x", c: true, d: "1" });
+      expect(result.issues?.[0]?.path).toEqual(["d"]);
+      expect(extended.jsonSchema.additionalProperties).toEqual({ type: "number" });
+    });
+
+    it("should reject non-object schemas", () => {
+      expect(() => a.merge(h.string() as any)).toThrow();
+    });
+  });
+
+  describe("shape()", () => {
+    const schema = h.object({ email: h.string().email(), address: { city: h.string() } });
+
+    it("should return the schema of a property", () => {
+      expect(schema.shape("email")["~standard"].validate("a@b.co")).toEqual({ value: "a@b.co" });
+      expect("issues" in schema.shape("email")["~standard"].validate("nope")).toBe(true);
+      expect(schema.shape("address").jsonSchema.properties.city).toEqual({ type: "string" });
+    });
+
+    it("should throw for an undeclared property", () => {
+      expect(() => schema.shape("missing" as any)).toThrow("Unknown property: missing");
+      expect(() => schema.shape("toString" as any)).toThrow("Unknown property: toString");
+    });
+  });
+
+  describe("keyof()", () => {
+    const keys = h.object({ id: h.number(), email: h.string() }).keyof();
+
+    it("should accept property names only", () => {
+      expect(keys["~standard"].validate("email")).toEqual({ value: "email" });
+      expect("issues" in keys["~standard"].validate("nope")).toBe(true);
+    });
+
+    it("should emit an enum of the keys", () => {
+      expect(keys.jsonSchema).toEqual({ type: "string", enum: ["id", "email"] });
+    });
+  });
+
+  describe("catchall()", () => {
+    const schema = h.object({ id: h.number() }).catchall(h.string());
+
+    it("should keep extra keys that match the catchall schema", () => {
+      expect(schema["~standard"].validate({ id: 1, env: "prod", region: "eu" })).toEqual({
+        value: { id: 1, env: "prod", region: "eu" },
+      });
+    });
+
+    it("should report extra keys that fail with their path", () => {
+      expect(schema["~standard"].validate({ id: 1, env: 2 })).toEqual({
+        issues: [
+          {
+            message: "Expected string, received number",
+            path: ["env"],
+            code: "invalid_type",
+            expected: "string",
+            received: "number",
+          },
+        ],
+      });
+    });
+
+    it("should still validate declared properties with their own schema", () => {
+      expect("issues" in schema["~standard"].validate({ id: "1" })).toBe(true);
+    });
+
+    it("should validate keys named after Object.prototype members", () => {
+      const input = JSON.parse('{"id":1,"toString":2,"constructor":"a","__proto__":3}');
+      const result = schema.validateWithOptions(input);
+      expect(result.issues?.map((issue) => issue.path)).toEqual([["toString"], ["__proto__"]]);
+      const valid = schema.validateWithOptions(JSON.parse('{"id":1,"__proto__":"a"}'));
+      expect(Object.getPrototypeOf(valid.value)).toBe(Object.prototype);
+      expect(Object.keys(valid.value ?? {})).toEqual(["id", "__proto__"]);
+    });
+
+    it("should emit additionalProperties", () => {
+      expect(schema.jsonSchema.additionalProperties).toEqual({ type: "string" });
+    });
+  });
+
+  describe("strict()", () => {
+    const schema = h.object({ name: h.string(), age: h.number() }).strict();
+
+    it("should report every unknown key alongside known property issues", () => {
+      const result = schema["~standard"].validate({ name: 1, age: 2, a: 1, b: 2, c: 3 });
+      expect(result).toEqual({
+        issues: [
+          {
+            message: "Expected string, received number",
+            path: ["name"],
+            code: "invalid_type",
+            expected: "string",
+            received: "number",
+          },
+          { message: "Unrecognized key: a", path: ["a"], code: "unrecognized_keys" },
+          { message: "Unrecognized key: b", path: ["b"], code: "unrecognized_keys" },
+          { message: "Unrecognized key: c", path: ["c"], code: "unrecognized_keys" },
+        ],
+      });
+    });
+
+    it("should stop at the first issue with abortEarly", () => {
+      const input = { name: "a", age: 1, a: 1, b: 2 };
+      expect(schema.validateWithOptions(input, { abortEarly: true }).issues).toHaveLength(1);
+    });
+
+    it("should report keys named after Object.prototype members", () => {
+      const input = JSON.parse('{"name":"a","age":1,"constructor":1,"toString":1,"__proto__":1}');
+      expect(schema.validateWithOptions(input).issues?.map((issue) => issue.message)).toEqual([
+        "Unrecognized key: constructor",
+        "Unrecognized key: toString",
+        "Unrecognized key: __proto__",
+      ]);
+    });
+
+    it("should accept objects with only declared keys", () => {
+      expect(schema["~standard"].validate({ name: "a", age: 1 })).toEqual({
+        value: { name: "a", age: 1 },
+      });
+      expect(schema.jsonSchema.additionalProperties).toBe(false);
+    });
+  });
+
+  describe("deepStrict()", () => {
+    const schema = h
+      .object({
+        user: h.object({ profile: h.object({ name: h.string() }) }),
+        tags: h.array(h.object({ label: h.string() })).optional(),
+      })
+      .deepStrict();
+
+    it("should reject unknown keys at nested levels", () => {
+      const input = { user: { profile: { name: "a", isAdmin: true } } };
+      expect(schema["~standard"].validate(input)).toEqual({
+        issues: [
+          {
+            message: "Unrecognized key: isAdmin",
+            path: ["user", "profile", "isAdmin"],
+            code: "unrecognized_keys",
+          },
+        ],
+      });
+      const tagged = { user: { profile: { name: "a" } }, tags: [{ label: "x", color: "red" }] };
+      expect(schema.validateWithOptions(tagged).issues?.[0]?.path).toEqual(["tags", 0, "color"]);
+    });
+
+    it("should accept objects with only declared keys", () => {
+      expect(schema["~standard"].validate({ user: { profile: { name: "a" } } })).toEqual({
+        value: { user: { profile: { name: "a" } } },
+      });
+      expect(schema.jsonSchema.properties.user.properties.profile.additionalProperties).toBe(false);
+    });
+
+    it("should override catchall()", () => {
+      const open = h.object({ nested: h.object({}).catchall(h.string()) }).deepStrict();
+      expect("issues" in open["~standard"].validate({ nested: { extra: "x" } })).toBe(true);
+    });
+
+    it("should descend through nullable, default and union wrappers", () => {
+      const item = h.object({ id: h.number() });
+      const wrapped = h
+        .object({
+          owner: item.nullable(),
+          settings: h.object({ theme: h.string() }).default({ theme: "dark" }),
+          target: h.options(item, h.string()),
+          pair: h.tuple([item, item]),
+          lookup: h.map(h.string(), item),
+        })
+        .deepStrict();
+      const valid = {
+        owner: null,
+        target: "x",
+        pair: [{ id: 1 }, { id: 2 }],
+        lookup: new Map([["a", { id: 1 }]]),
+      };
+      expect(wrapped.validateWithOptions(valid).value?.settings).toEqual({ theme: "dark" });
+      const paths = [
+        { owner: { id: 1, extra: 1 } },
+        { settings: { theme: "a", extra: 1 } },
+        { target: { id: 1, extra: 1 } },
+        { pair: [{ id: 1 }, { id: 2, extra: 1 }] },
+        { lookup: new Map([["a", { id: 1, extra: 1 }]]) },
+      ].map((patch) => wrapped.validateWithOptions({ ...valid, ...patch }).issues?.[0]?.path);
+      expect(paths).toEqual([
+        ["owner", "extra"],
+        ["settings", "extra"],
+        ["target", "extra"],
+        ["pair", 1, "extra"],
+        ["lookup", 0, "value", "extra"],
+      ]);
+      expect(wrapped.jsonSchema.properties.owner.additionalProperties).toBe(false);
+    });
+
+    it("should descend into overrideAt() schemas", () => {
+      const header = h.array(h.string()).overrideAt(0, h.object({ id: h.number() }));
+      const row = h.object({ header }).deepStrict();
+      const input = { header: [{ id: 1, extra: 1 }, "a"] };
+      expect(row.validateWithOptions(input).issues?.[0]?.path).toEqual(["header", 0, "extra"]);
+      expect(row.jsonSchema.properties.header.prefixItems[0].additionalProperties).toBe(false);
+    });
+
+    type Node = { name: string; children: Node[] };
+    const node: ObjectSchemaType<Node> = h.object({
+      name: h.string(),
+      children: h.array(h.lazy(() => node)),
+    });
+
+    it("should descend into lazy schemas", () => {
+      const tree = h.object({ root: node }).deepStrict();
+      const input = { root: { name: "a", children: [{ name: "b", children: [], extra: 1 }] } };
+      expect(tree.validateWithOptions(input).issues?.[0]?.path).toEqual([
+        "root",
+        "children",
+        0,
+        "extra",
+      ]);
+    });
+
+    it("should report deeply nested input instead of overflowing the stack", () => {
+      let root: Node = { name: "leaf", children: [] };
+      for (let i = 0; i < 10000; i++) {
+        root = { name: "node", children: [root] };
+      }
+      const wrapper = h.object({ root: node });
+      for (const tree of [wrapper.deepStrict(), wrapper.deepPartial()]) {
+        expect(tree.validateWithOptions({ root }).issues?.[0]?.message).toBe(
+          "Maximum recursion depth exceeded",
+        );
+        const shallow = tree.validateWithOptions({ root }, { maxDepth: 2 });
+        expect(shallow.issues?.[0]?.path).toEqual([
+          "root",
+          "children",
+          0,
+          "children",
+          0,
+          "children",
+          0,
+        ]);
+      }
+    });
+
+    it("should stop on cyclic data instead of overflowing the stack", () => {
+      const cyclic: any = { name: "a", children: [] };
+      cyclic.children.push(cyclic);
+      const wrapper = h.object({ root: node });
+      for (const tree of [wrapper.deepStrict(), wrapper.deepPartial()]) {
+        expect(tree.validateWithOptions({ root: cyclic }).issues?.[0]?.message).toBe(
+          "Maximum recursion depth exceeded",
+        );
+      }
+    });
+
+    it("should keep the top-level configuration", () => {
+      const strict = h
+        .object({ userName: h.string() })
+        .keyTransform((key) => key.replace(/_(\w)/g, (_, c: string) => c.toUpperCase()))
+        .refine((value) => value.userName !== "root", "Reserved name")
+        .describe("User")
+        .deepStrict();
+      expect(strict.validateWithOptions({ user_name: "ada" })).toEqual({
+        value: { userName: "ada" },
+      });
+      expect(strict.validateWithOptions({ user_name: "root" }).issues?.[0]?.message).toBe(
+        "Reserved name",
+      );
+      expect(strict.jsonSchema.description).toBe("User");
+    });
+
+    it("should keep discriminated union tags under deepPartial()", () => {
+      const event = h.object({
+        event: h.discriminatedUnion("type", [
+          h.object({ type: h.literal("click"), x: h.number() }),
+          h.object({ type: h.literal("key"), code: h.string() }),
+        ]),
+      });
+      const patch = event.deepPartial();
+      expect(patch.validateWithOptions({ event: { type: "click" } })).toEqual({
+        value: { event: { type: "click" } },
+      });
+      expect("issues" in patch.validateWithOptions({ event: { x: 1 } })).toBe(true);
+    });
+  });
+
+  describe("xor() / atMostOne() / atLeastOne()", () => {
+    const contact = h.object({
+      email: h.string().optional(),
+      phone: h.string().optional(),
+      fax: h.string().optional(),
+    });
+
+    it("should require exactly one of the keys with xor()", () => {
+      const schema = contact.xor(["email", "phone"]);
+      expect(schema["~standard"].validate({ email: "a@b.co" })).toEqual({
+        value: { email: "a@b.co" },
+      });
+      const issue = { message: "Exactly one of email, phone must be provided", code: "custom" };
+      expect(schema["~standard"].validate({ email: "a@b.co", phone: "1" })).toEqual({
+        issues: [issue],
+      });
+      expect(schema["~standard"].validate({ phone: undefined })).toEqual({ issues: [issue] });
+    });
+
+    it("should bound the number of provided keys", () => {
+      const atMostOne = contact.atMostOne(["phone", "fax"]);
+      expect("value" in atMostOne["~standard"].validate({})).toBe(true);
+      expect(atMostOne["~standard"].validate({ phone: "1", fax: "2" })).toEqual({
+        issues: [{ message: "At most one of phone, fax may be provided", code: "custom" }],
+      });
+      const atLeastOne = contact.atLeastOne(["email", "phone", "fax"]);
+      expect("value" in atLeastOne["~standard"].validate({ email: "a", fax: "2" })).toBe(true);
+      expect(atLeastOne["~standard"].validate({})).toEqual({
+        issues: [{ message: "At least one of email, phone, fax must be provided", code: "custom" }],
+      });
+    });
+
+    it("should emit the groups in allOf", () => {
+      const schema = contact.xor(["email", "phone"]).atMostOne(["phone", "fax"]);
+      expect(schema.jsonSchema.allOf).toEqual([
+        { oneOf: [{ required: ["email"] }, { required: ["phone"] }] },
+        { not: { anyOf: [{ required: ["phone", "fax"] }] } },
+      ]);
+    });
+  });
+
+  describe("requireIf()", () => {
+    const order = h
+      .object({
+        hasShipping: h.boolean(),
+        address: h.object({ city: h.string() }).optional(),
+      })
+      .requireIf("hasShipping", (hasShipping) => hasShipping, ["address"]);
+
+    it("should require the keys when the predicate matches", () => {
+      expect(order["~standard"].validate({ hasShipping: true })).toEqual({
+        issues: [
+          {
+            message: "Missing required property: address",
+            path: ["address"],
+            code: "invalid_type",
+            expected: "object",
+            received: "undefined",
+          },
+        ],
+      });
+      const shipped = { hasShipping: true, address: { city: "Lima" } };
+      expect(order["~standard"].validate(shipped)).toEqual({ value: shipped });
+    });
+
+    it("should leave the keys optional otherwise", () => {
+      expect(order["~standard"].validate({ hasShipping: false })).toEqual({
+        value: { hasShipping: false },
+      });
+    });
+
+    it("should still validate provided keys and skip an invalid trigger", () => {
+      const invalid = order.validateWithOptions({ hasShipping: true, address: { city: 1 } });
+      expect(invalid.issues?.map((issue) => issue.path)).toEqual([["address", "city"]]);
+      const badTrigger = order.validateWithOptions({ hasShipping: "yes" });
+      expect(badTrigger.issues?.map((issue) => issue.path)).toEqual([["hasShipping"]]);
+    });
+
+    it("should drop rules whose keys were omitted or not picked", () => {
+      const withoutAddress = order.omit(["address"]);
+      expect(withoutAddress.validateWithOptions({ hasShipping: true })).toEqual({
+        value: { hasShipping: true },
+      });
+      const contact = h
+        .object({ email: h.string().optional(), phone: h.string().optional(), id: h.number() })
+        .xor(["email", "phone"])
+        .atLeastOne(["id"]);
+      const byId = contact.pick(["id"]);
+      expect(byId.validateWithOptions({ id: 1 })).toEqual({ value: { id: 1 } });
+      expect(byId.jsonSchema.allOf).toEqual([{ anyOf: [{ required: ["id"] }] }]);
+      expect(contact.omit(["id", "phone"]).jsonSchema.allOf).toBeUndefined();
+    });
+  });
+
+  describe("keyTransform()", () => {
+    const toCamel = (key: string) => key.replace(/_([a-z])/g, (_, c: string) => c.toUpperCase());
+    const schema = h.object({ userName: h.string(), isAdmin: h.boolean() }).keyTransform(toCamel);
+
+    it("should read renamed keys and output the property names", () => {
+      expect(schema["~standard"].validate({ user_name: "x", is_admin: false })).toEqual({
+        value: { userName: "x", isAdmin: false },
+      });
+    });
+
+    it("should report issues at the renamed path", () => {
+      expect(schema["~standard"].validate({ user_name: 1, is_admin: true })).toEqual({
+        issues: [
+          {
+            message: "Expected string, received number",
+            path: ["userName"],
+            code: "invalid_type",
+            expected: "string",
+            received: "number",
+          },
+        ],
+      });
+    });
+
+    it("should keep a renamed __proto__ key as an own property", () => {
+      const rename = (key: string) => (key === "user_name" ? "userName" : key);
+      const input = JSON.parse('{"user_name":"x","__proto__":{"role":"admin"}}');
+      const user = h.object({ role: h.string().optional(), userName: h.string() });
+      expect(
+        user
+          .keyTransform(rename)
+          .strict()
+          .validateWithOptions(input)
+          .issues?.map((issue) => issue.message),
+      ).toEqual(["Unrecognized key: __proto__"]);
+      const guarded = user.keyTransform(rename).atLeastOne(["role"]);
+      expect(guarded.validateWithOptions(input).issues?.[0]?.message).toBe(
+        "At least one of role must be provided",
+      );
+    });
+
+    it("should report keys renamed onto a name that is already taken", () => {
+      const input = { user_name: "x", userName: "y", is_admin: true };
+      expect(schema.validateWithOptions(input)).toEqual({
+        issues: [
+          {
+            message: "Duplicate key after renaming userName: userName",
+            path: ["userName"],
+            code: "custom",
+          },
+        ],
+      });
+    });
+  });
+
+  describe("JSON Schema output", () => {
+    it("should not let consumers mutate the schemas it was built from", () => {
+      const name = h.string();
+      const user = h.object({ name });
+      const json = h.getJsonSchema(user) as { properties: { name: Record<string, unknown> } };
+      json.properties.name.minLength = 3;
+      expect(name.jsonSchema).toEqual({ type: "string" });
+      expect(user.jsonSchema.properties.name).toEqual({ type: "string" });
+      expect(h.getJsonSchema(user)).toEqual({
+        type: "object",
+        properties: { name: { type: "string" } },
+        required: ["name"],
+      });
+    });
+
+    it("should keep default values as they are", () => {
+      const since = new Date(0);
+      const json = h.getJsonSchema(h.dateType().default(since)) as { default: unknown };
+      expect(json.default).toBe(since);
+    });
+  });
+
+  describe("toJsonSchema()", () => {
+    const point = h.tuple([h.number(), h.number()]);
+
+    it("should emit the 2020-12 $schema and prefixItems by default", () => {
+      expect(point.toJsonSchema()).toEqual({
+        $schema: "https://json-schema.org/draft/2020-12/schema",
+        type: "array",
+        prefixItems: [{ type: "number" }, { type: "number" }],
+        items: false,
+        minItems: 2,
+        maxItems: 2,
+      });
+    });
+
+    it("should use the items tuple form for draft-07", () => {
+      const shape = h.object({ points: h.array(point), name: h.string().default("path") });
+      expect(shape.toJsonSchema("draft-07")).toEqual({
+        $schema: "http://json-schema.org/draft-07/schema#",
+        type: "object",
+        properties: {
+          points: {
+            type: "array",
+            items: {
+              type: "array",
+              items: [{ type: "number" }, { type: "number" }],
+              additionalItems: false,
+              minItems: 2,
+              maxItems: 2,
+            },
+          },
+          name: { type: "string", default: "path" },
+        },
+        required: ["points"],
+      });
+      expect(h.getJsonSchema(point, { target: "draft-07" }).additionalItems).toBe(false);
+    });
+  });
+
+  describe("id()", () => {
+    const address = h.object({ street: h.string(), city: h.string() }).id("Address");
+    const order = h.object({
+      billing: address,
+      shipping: address.optional(),
+      stops: h.array(address),
+    });
+
+    it("should emit a named schema once under $defs", () => {
+      expect(h.getJsonSchema(order)).toEqual({
+        type: "object",
+        properties: {
+          billing: { $ref: "#/$defs/Address" },
+          shipping: { $ref: "#/$defs/Address" },
+          stops: { type: "array", items: { $ref: "#/$defs/Address" } },
+        },
+        required: ["billing", "stops"],
+        $defs: {
+          Address: {
+            type: "object",
+            properties: { street: { type: "string" }, city: { type: "string" } },
+            required: ["street", "city"],
+          },
+        },
+      });
+    });
+
+    it("should keep keys added on top of the named schema", () => {
+      const json = h.getJsonSchema(h.object({ a: address.describe("Home") }));
+      expect(json.properties).toEqual({ a: { $ref: "#/$defs/Address", description: "Home" } });
+    });
+
+    it("should use definitions for draft-07", () => {
+      const json = h.getJsonSchema(order, { target: "draft-07" });
+      expect(Object.keys(json.definitions as object)).toEqual(["Address"]);
+      expect((json.properties as any).billing).toEqual({ $ref: "#/definitions/Address" });
+    });
+
+    it("should not change validation", () => {
+      expect("issues" in order["~standard"].validate({ billing: {}, stops: [] })).toBe(true);
+    });
+  });
+});
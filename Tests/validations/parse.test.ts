@@ -0,0 +1,138 @@
+import { describe, expect, it } from "bun:test";
+import { flattenIssues, formatIssues, h, ValidationError } from "@hedystia/validations";
+
+describe("Parsing", () => {
+  const schema = h.object({ name: h.string() });
+
+  describe("parse()", () => {
+    it("should return the parsed value", () => {
+      expect(schema.parse({ name: "ada" })).toEqual({ name: "ada" });
+    });
+
+    it("should throw a ValidationError carrying the issues", () => {
+      try {
+        schema.parse({ name: 1 });
+        throw new Error("parse() did not throw");
+      } catch (error) {
+        expect(error).toBeInstanceOf(ValidationError);
+        expect((error as ValidationError).issues).toEqual([
+          {
+            message: "Expected string, received number",
+            path: ["name"],
+            code: "invalid_type",
+            expected: "string",
+            received: "number",
+          },
+        ]);
+      }
+    });
+  });
+
+  describe("safeParse()", () => {
+    it("should return success with the data", () => {
+      expect(schema.safeParse({ name: "ada" })).toEqual({ success: true, data: { name: "ada" } });
+    });
+
+    it("should return the issues without throwing", () => {
+      const result = schema.safeParse({});
+      expect(result.success).toBe(false);
+      expect(!result.success && result.error.issues[0]?.path).toEqual(["name"]);
+    });
+  });
+
+  describe("validateAt()", () => {
+    const user = h.object({ email: h.string().email(), tags: h.array(h.string()) });
+
+    it("should prefix issue paths with the given location", () => {
+      const result = user.validateAt({ email: "nope", tags: [1] }, ["body", "user"]);
+      expect(result.issues?.map((issue) => issue.path)).toEqual([
+        ["body", "user", "email"],
+        ["body", "user", "tags", 0],
+      ]);
+    });
+
+    it("should use the prefix as the path of root issues", () => {
+      expect(h.string().validateAt(1, ["query", "q"]).issues?.[0]?.path).toEqual(["query", "q"]);
+    });
+
+    it("should return the value unchanged on success", () => {
+      expect(user.validateAt({ email: "a@b.co", tags: [] }, ["body"])).toEqual({
+        value: { email: "a@b.co", tags: [] },
+      });
+    });
+  });
+
+  describe("validateMany()", () => {
+    const rows = [{ name: "ada" }, { name: 1 }, { name: "grace" }, {}];
+
+    it("should return one result per item", () => {
+      const results = schema.validateMany(rows);
+      expect(results.map((result) => "value" in result)).toEqual([true, false, true, false]);
+    });
+
+    it("should return only the failing indices with their issues", () => {
+      expect(schema.validateMany(rows, { failuresOnly: true })).toEqual([
+        {
+          index: 1,
+          issues: [
+            {
+              message: "Expected string, received number",
+              path: ["name"],
+              code: "invalid_type",
+              expected: "string",
+              received: "number",
+            },
+          ],
+        },
+        {
+          index: 3,
+          issues: [
+            {
+              message: "Missing required property: name",
+              path: ["name"],
+              code: "invalid_type",
+              expected: "string",
+              received: "undefined",
+            },
+          ],
+        },
+      ]);
+    });
+
+    it("should stop at the first failing item with bail", () => {
+      expect(schema.validateMany(rows, { bail: true })).toHaveLength(2);
+      expect(schema.validateMany(rows, { bail: true, failuresOnly: true })).toHaveLength(1);
+    });
+  });
+
+  describe("flattenIssues() / formatIssues()", () => {
+    const signup = h
+      .object({ email: h.string().email(), address: h.object({ zip: h.string().minLength(5) }) })
+      .refine((value) => value.email !== "admin@example.com", "Reserved account");
+
+    it("should group messages by top-level field", () => {
+      const result = signup.safeParse({ email: "nope", address: { zip: "1" } });
+      expect(!result.success && flattenIssues(result.error.issues)).toEqual({
+        formErrors: [],
+        fieldErrors: { email: ["Invalid email format"], address: ["String shorter than 5"] },
+      });
+    });
+
+    it("should keep root issues in formErrors", () => {
+      const result = signup.safeParse({ email: "admin@example.com", address: { zip: "12345" } });
+      expect(!result.success && flattenIssues(result.error.issues)).toEqual({
+        formErrors: ["Reserved account"],
+        fieldErrors: {},
+      });
+    });
+
+    it("should nest messages following the paths", () => {
+      const result = signup.safeParse({ email: "nope", address: { zip: "1" } });
+      expect(!result.success && formatIssues(result.error.issues)).toEqual({
+        _errors: [],
+        email: { _errors: ["Invalid email format"] },
+        address: { _errors: [], zip: { _errors: ["String shorter than 5"] } },
+      });
+    });
+  });
+});
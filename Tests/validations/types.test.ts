@@ -0,0 +1,1397 @@
+import { describe, expect, it } from "bun:test";
+import {
+  type BaseSchema,
+  type ErrorMapIssue,
+  h,
+  type ObjectSchemaType,
+  registerFormat,
+  setErrorMap,
+} from "@hedystia/validations";
+
+describe("Validation types", () => {
+  describe("builders", () => {
+    it("should validate with the derived schema configuration", () => {
+      const schema = h.string().minLength(3);
+      expect("issues" in schema["~standard"].validate("ab")).toBe(true);
+      expect(schema["~standard"].validate("abc")).toEqual({ value: "abc" });
+    });
+
+    it("should expose ~standard as a stable own enumerable property", () => {
+      const base = h.string();
+      const derived = base.minLength(3);
+      expect(base["~standard"]).toBe(base["~standard"]);
+      expect(derived["~standard"]).not.toBe(base["~standard"]);
+      expect(Object.keys(derived)).toContain("~standard");
+      expect({ ...derived }["~standard"]).toBe(derived["~standard"]);
+      expect("issues" in derived.array()["~standard"].validate(["ab"])).toBe(true);
+    });
+
+    it("should leave the base schema unchanged when deriving", () => {
+      const base = h.number();
+      const positive = base.min(0);
+      const bounded = positive.max(10);
+      expect(base.jsonSchema).toEqual({ type: "number" });
+      expect(positive.jsonSchema).toEqual({ type: "number", minimum: 0 });
+      expect(bounded.jsonSchema).toEqual({ type: "number", minimum: 0, maximum: 10 });
+      expect(base["~standard"].validate(-1)).toEqual({ value: -1 });
+    });
+
+    it("should not coerce the base schema", () => {
+      const base = h.number();
+      base.coerce();
+      expect("issues" in base["~standard"].validate("1")).toBe(true);
+    });
+
+    it("should keep earlier checks when adding a format", () => {
+      const formats = [
+        h.string().minLength(20).email(),
+        h.string().minLength(20).uuid(),
+        h.string().minLength(20).phone(),
+        h.string().minLength(20).domain(false),
+        h.string().minLength(20).regex(/^[a-z]+$/),
+      ];
+      for (const schema of formats) {
+        expect(schema["~standard"].validate("a")).toEqual({
+          issues: [{ message: "String shorter than 20", code: "too_small" }],
+        });
+        expect(schema.jsonSchema.minLength).toBe(20);
+      }
+    });
+
+    it("should honor regex flags", () => {
+      const schema = h.string().regex(/^abc$/gi);
+      expect(schema["~standard"].validate("ABC")).toEqual({ value: "ABC" });
+      expect(schema["~standard"].validate("ABC")).toEqual({ value: "ABC" });
+    });
+  });
+
+  describe("issue codes", () => {
+    const codeOf = (schema: BaseSchema<unknown, unknown>, value: unknown) =>
+      schema.validateWithOptions(value).issues?.map((issue) => issue.code);
+
+    it("should tag each issue with its kind", () => {
+      expect(codeOf(h.string(), 1)).toEqual(["invalid_type"]);
+      expect(codeOf(h.string().email(), "nope")).toEqual(["invalid_string"]);
+      expect(codeOf(h.number().min(1), 0)).toEqual(["too_small"]);
+      expect(codeOf(h.number().max(1), 2)).toEqual(["too_big"]);
+      expect(codeOf(h.literal("a"), "b")).toEqual(["invalid_literal"]);
+    });
+
+    it("should propagate child codes through unions and objects", () => {
+      const schema = h.object({ id: h.options(h.string().minLength(3), h.number()) });
+      expect(codeOf(schema, { id: "ab" })).toEqual(["too_small", "invalid_type"]);
+    });
+  });
+
+  describe("expected / received", () => {
+    it("should describe type mismatches", () => {
+      const result = h.string()["~standard"].validate(42);
+      expect("issues" in result && result.issues[0]).toEqual({
+        message: "Expected string, received number",
+        code: "invalid_type",
+        expected: "string",
+        received: "number",
+      });
+    });
+
+    it("should tell null and arrays apart from objects in messages", () => {
+      const messages = [null, [], {}, Number.NaN, () => 1].flatMap((value) => [
+        ...(h.string().validateWithOptions(value).issues ?? []),
+        ...(h.literal("a").validateWithOptions(value).issues ?? []),
+      ]);
+      expect(messages.map((issue) => issue.message)).toEqual([
+        "Expected string, received null",
+        "Expected literal value a, received null",
+        "Expected string, received array",
+        "Expected literal value a, received array",
+        "Expected string, received object",
+        "Expected literal value a, received object",
+        "Expected string, received NaN",
+        "Expected literal value a, received NaN",
+        "Expected string, received function",
+        "Expected literal value a, received function",
+      ]);
+      expect(h.number().validateWithOptions(Number.NaN).issues?.[0]?.received).toBe("NaN");
+    });
+
+    it("should not be set on other issues", () => {
+      const result = h.string().minLength(3).validateWithOptions("a");
+      expect(result.issues?.[0]?.expected).toBeUndefined();
+      expect(result.issues?.[0]?.received).toBeUndefined();
+    });
+  });
+
+  describe("custom messages", () => {
+    it("should use the message passed to a check", () => {
+      const password = h.string().minLength(8, "Password too short");
+      expect(password["~standard"].validate("abc")).toEqual({
+        issues: [{ message: "Password too short", code: "too_small" }],
+      });
+      expect(h.string().email("Enter a valid email")["~standard"].validate("nope")).toEqual({
+        issues: [{ message: "Enter a valid email", code: "invalid_string" }],
+      });
+      expect(h.number().max(10, "At most ten")["~standard"].validate(11)).toEqual({
+        issues: [{ message: "At most ten", code: "too_big" }],
+      });
+    });
+
+    it("should fall back to the default message for other checks", () => {
+      const schema = h.string().minLength(2, "Too short").maxLength(3);
+      expect(schema["~standard"].validate("abcd")).toEqual({
+        issues: [{ message: "String longer than 3", code: "too_big" }],
+      });
+    });
+  });
+
+  describe("setErrorMap()", () => {
+    const withMap = (run: () => void) => {
+      setErrorMap((issue) => {
+        if (issue.code === "too_small") return `Mínimo ${issue.min}`;
+        if (issue.code === "invalid_type") return `Se esperaba ${issue.expected}`;
+        return undefined;
+      });
+      try {
+        run();
+      } finally {
+        setErrorMap(undefined);
+      }
+    };
+
+    it("should replace built-in messages using the issue details", () => {
+      withMap(() => {
+        expect(h.string().minLength(3)["~standard"].validate("ab")).toEqual({
+          issues: [{ message: "Mínimo 3", code: "too_small" }],
+        });
+        expect(h.object({ n: h.number() })["~standard"].validate({ n: "1" })).toEqual({
+          issues: [
+            {
+              message: "Se esperaba number",
+              path: ["n"],
+              code: "invalid_type",
+              expected: "number",
+              received: "string",
+            },
+          ],
+        });
+      });
+    });
+
+    it("should keep custom messages and unmapped codes", () => {
+      withMap(() => {
+        expect(h.number().min(1, "Too low").validateWithOptions(0)).toEqual({
+          issues: [{ message: "Too low", code: "too_small" }],
+        });
+        expect(h.number().max(1)["~standard"].validate(2)).toEqual({
+          issues: [{ message: "Number greater than 1", code: "too_big" }],
+        });
+      });
+    });
+
+    it("should map issues from array, date, tuple and object checks", () => {
+      const seen: ErrorMapIssue[] = [];
+      setErrorMap((issue) => {
+        seen.push(issue);
+        return `mapped ${issue.code}`;
+      });
+      try {
+        const messageOf = (schema: { validateWithOptions(v: unknown): any }, value: unknown) =>
+          schema.validateWithOptions(value).issues?.[0]?.message;
+        expect(messageOf(h.array(h.number()).unique(), [1, 1])).toBe("mapped not_unique");
+        expect(messageOf(h.array(h.number()).ascending(), [2, 1])).toBe("mapped not_sorted");
+        expect(messageOf(h.dateType(), new Date(Number.NaN))).toBe("mapped invalid_date");
+        expect(messageOf(h.tuple([h.string()]), [])).toBe("mapped too_small");
+        expect(messageOf(h.number().step(0, 3), 4)).toBe("mapped not_multiple_of");
+        const contact = h.object({ a: h.string().optional(), b: h.string().optional() });
+        expect(messageOf(contact.xor(["a", "b"]), {})).toBe("mapped custom");
+      } finally {
+        setErrorMap(undefined);
+      }
+      expect(seen.find((issue) => issue.code === "too_small")?.min).toBe(1);
+      expect(seen.find((issue) => issue.code === "not_multiple_of")?.multipleOf).toBe(3);
+    });
+
+    it("should pass the issue path to the map", () => {
+      const paths: unknown[] = [];
+      setErrorMap((issue) => {
+        paths.push(issue.path);
+        return undefined;
+      });
+      h.object({ tags: h.array(h.string()) })["~standard"].validate({ tags: ["a", 1] });
+      setErrorMap(undefined);
+      expect(paths).toEqual([["tags", 1]]);
+    });
+  });
+
+  describe("trim() / nonempty()", () => {
+    it("should trim the output", () => {
+      expect(h.string().trim()["~standard"].validate("  ada ")).toEqual({ value: "ada" });
+    });
+
+    it("should reject empty strings with a dedicated message and code", () => {
+      const empty = { issues: [{ message: "String must not be empty", code: "empty_string" }] };
+      expect(h.string().nonempty()["~standard"].validate("")).toEqual(empty);
+      expect(h.string().nonempty().trim()["~standard"].validate("   ")).toEqual(empty);
+      expect(h.string().nonempty()["~standard"].validate("   ")).toEqual({ value: "   " });
+      expect(h.string().nonempty().jsonSchema).toEqual({ type: "string", minLength: 1 });
+    });
+  });
+
+  describe("normalize()", () => {
+    const decomposed = "e\u0301";
+
+    it("should compose characters before the length checks", () => {
+      const schema = h.string().normalize().maxLength(1);
+      expect(schema["~standard"].validate(decomposed)).toEqual({ value: "\u00e9" });
+    });
+
+    it("should apply the requested form", () => {
+      expect(h.string().normalize("NFD")["~standard"].validate("\u00e9")).toEqual({
+        value: decomposed,
+      });
+      expect(h.string().normalize("NFKC")["~standard"].validate("\uFB01")).toEqual({ value: "fi" });
+    });
+  });
+
+  describe("minBytes() / maxBytes()", () => {
+    it("should bound the UTF-8 byte length rather than the string length", () => {
+      const schema = h.string().maxLength(2).maxBytes(3);
+      expect(h.string().maxLength(2)["~standard"].validate("éé")).toEqual({ value: "éé" });
+      expect(schema["~standard"].validate("éé")).toEqual({
+        issues: [{ message: "String longer than 3 bytes", code: "too_big" }],
+      });
+      expect(h.string().maxBytes(4)["~standard"].validate("😀")).toEqual({ value: "😀" });
+      expect("issues" in h.string().maxBytes(4)["~standard"].validate("😀😀")).toBe(true);
+    });
+
+    it("should reject strings below the minimum byte length", () => {
+      const schema = h.string().minBytes(4);
+      expect(schema["~standard"].validate("ab")).toEqual({
+        issues: [{ message: "String shorter than 4 bytes", code: "too_small" }],
+      });
+      expect(schema["~standard"].validate("é€")).toEqual({ value: "é€" });
+    });
+
+    it("should emit byte annotations", () => {
+      expect(h.string().minBytes(1).maxBytes(255).jsonSchema).toEqual({
+        type: "string",
+        "x-minBytes": 1,
+        "x-maxBytes": 255,
+      });
+    });
+  });
+
+  describe("noEmoji() / noControlChars()", () => {
+    it("should report the index of the first emoji", () => {
+      const username = h.string().noEmoji();
+      expect(username["~standard"].validate("hi")).toEqual({ value: "hi" });
+      expect(username["~standard"].validate("hi🚀")).toEqual({
+        issues: [{ message: "String contains an emoji at index 2", code: "invalid_string" }],
+      });
+      expect("issues" in username["~standard"].validate("🇫🇷")).toBe(true);
+    });
+
+    it("should reject control characters", () => {
+      const schema = h.string().noControlChars();
+      expect(schema["~standard"].validate("a b")).toEqual({ value: "a b" });
+      expect(schema["~standard"].validate("a\u0000b")).toEqual({
+        issues: [
+          { message: "String contains a control character at index 1", code: "invalid_string" },
+        ],
+      });
+    });
+
+    it("should compose with length checks", () => {
+      const schema = h.string().noEmoji().noControlChars().minLength(3);
+      expect("issues" in schema["~standard"].validate("ab")).toBe(true);
+      expect("issues" in schema["~standard"].validate("abc\n")).toBe(true);
+      expect(schema["~standard"].validate("abc")).toEqual({ value: "abc" });
+    });
+  });
+
+  describe("includesAny() / excludes()", () => {
+    it("should require at least one of the substrings", () => {
+      const schema = h.string().includesAny(["@", "#"]);
+      expect(schema["~standard"].validate("#general")).toEqual({ value: "#general" });
+      expect(schema["~standard"].validate("no at sign")).toEqual({
+        issues: [{ message: 'String must include one of "@" | "#"', code: "invalid_string" }],
+      });
+    });
+
+    it("should name the forbidden substring that was found", () => {
+      const schema = h.string().excludes(["spam", "badword"]);
+      expect(schema["~standard"].validate("all good")).toEqual({ value: "all good" });
+      expect(schema["~standard"].validate("a badword b")).toEqual({
+        issues: [{ message: 'String must not include "badword"', code: "invalid_string" }],
+      });
+    });
+  });
+
+  describe("password()", () => {
+    const password = h.string().password();
+
+    it("should report every unmet requirement", () => {
+      expect(password["~standard"].validate("abc")).toEqual({
+        issues: [
+          { message: "Password must be at least 8 characters", code: "too_small" },
+          { message: "Password must contain an uppercase letter", code: "invalid_string" },
+          { message: "Password must contain a digit", code: "invalid_string" },
+          { message: "Password must contain a symbol", code: "invalid_string" },
+        ],
+      });
+      expect(password["~standard"].validate("Str0ng!pass")).toEqual({ value: "Str0ng!pass" });
+    });
+
+    it("should only check the enabled requirements", () => {
+      const pin = h.string().password({ minLength: 4, upper: false, lower: false, symbol: false });
+      expect(pin["~standard"].validate("1234")).toEqual({ value: "1234" });
+      expect(pin.validateWithOptions("12").issues).toHaveLength(1);
+    });
+
+    it("should report a single issue when aborting early or with a custom message", () => {
+      expect(password.validateWithOptions("abc", { abortEarly: true }).issues).toHaveLength(1);
+      expect(h.string().password({}, "Weak password")["~standard"].validate("abc")).toEqual({
+        issues: [{ message: "Weak password", code: "too_small" }],
+      });
+    });
+
+    it("should emit the password format and minimum length", () => {
+      expect(password.jsonSchema).toEqual({ type: "string", format: "password", minLength: 8 });
+    });
+  });
+
+  describe("h.datetime() / h.datetimeLocal()", () => {
+    it("should require a valid date-time with an offset", () => {
+      for (const value of ["2023-01-01T12:00:00Z", "2024-02-29T23:59:59.123+05:30"]) {
+        expect(h.datetime()["~standard"].validate(value)).toEqual({ value });
+      }
+      for (const value of ["2023-01-01T12:00:00", "2023-02-29T12:00:00Z", "2023-01-01T24:00Z"]) {
+        expect(h.datetime()["~standard"].validate(value)).toEqual({
+          issues: [{ message: "Invalid datetime format", code: "invalid_string" }],
+        });
+      }
+      expect(h.datetime().jsonSchema).toEqual({ type: "string", format: "date-time" });
+    });
+
+    it("should convert the offset to UTC when normalizing", () => {
+      const schema = h.datetime(true);
+      expect(schema["~standard"].validate("2023-01-01T12:00:00+02:00")).toEqual({
+        value: "2023-01-01T10:00:00Z",
+      });
+      expect(schema["~standard"].validate("2023-01-01T01:30:00.5-03:15")).toEqual({
+        value: "2023-01-01T04:45:00.5Z",
+      });
+      expect(schema["~standard"].validate("2024-01-01T00:30+01:00")).toEqual({
+        value: "2023-12-31T23:30:00Z",
+      });
+    });
+
+    it("should reject date-times that leave the four-digit years in UTC", () => {
+      const schema = h.datetime(true);
+      for (const value of ["0000-01-01T00:30:00+01:00", "9999-12-31T23:30:00-01:00"]) {
+        expect(schema["~standard"].validate(value)).toEqual({
+          issues: [{ message: "Datetime is out of range in UTC", code: "invalid_string" }],
+        });
+      }
+      expect(schema["~standard"].validate("0000-01-01T01:00:00+01:00")).toEqual({
+        value: "0000-01-01T00:00:00Z",
+      });
+    });
+
+    it("should reject offsets for local date-times", () => {
+      expect(h.datetimeLocal()["~standard"].validate("2023-01-01T12:00")).toEqual({
+        value: "2023-01-01T12:00",
+      });
+      expect("issues" in h.datetimeLocal()["~standard"].validate("2023-01-01T12:00Z")).toBe(true);
+    });
+  });
+
+  describe("h.hexColor()", () => {
+    it("should accept 3 and 6 digit colors case-insensitively", () => {
+      const schema = h.hexColor();
+      for (const color of ["#fff", "#A1B2C3"]) {
+        expect(schema["~standard"].validate(color)).toEqual({ value: color });
+      }
+      for (const color of ["fff", "#GGG", "#ffff", "#fffff", "#ffffffff"]) {
+        expect("issues" in schema["~standard"].validate(color)).toBe(true);
+      }
+      expect(schema.jsonSchema).toEqual({ type: "string", format: "color" });
+    });
+
+    it("should accept alpha channels when allowed", () => {
+      const schema = h.hexColor(true);
+      for (const color of ["#fff", "#ffff", "#ffffff", "#ffffff80"]) {
+        expect(schema["~standard"].validate(color)).toEqual({ value: color });
+      }
+      expect("issues" in schema["~standard"].validate("#fffff")).toBe(true);
+    });
+  });
+
+  describe("h.semver()", () => {
+    const schema = h.semver();
+
+    it("should accept versions with pre-release and build metadata", () => {
+      const valid = ["1.2.3", "0.0.0", "1.2.3-rc.1+build.7", "1.0.0-alpha-1", "1.0.0+001"];
+      for (const version of valid) {
+        expect(schema["~standard"].validate(version)).toEqual({ value: version });
+      }
+      expect(schema.jsonSchema).toEqual({ type: "string", format: "semver" });
+    });
+
+    it("should reject incomplete versions and leading zeros", () => {
+      const invalid = ["1.2", "1.01.0", "01.0.0", "1.0.0-01", "1.0.0-", "1.0.0+", "v1.0.0"];
+      for (const version of invalid) {
+        expect("issues" in schema["~standard"].validate(version)).toBe(true);
+      }
+    });
+  });
+
+  describe("h.emoji()", () => {
+    it("should accept exactly one emoji by default", () => {
+      const schema = h.emoji();
+      for (const emoji of ["🚀", "👨‍👩‍👧", "👍🏽", "🇺🇸", "❤️", "1️⃣"]) {
+        expect(schema["~standard"].validate(emoji)).toEqual({ value: emoji });
+      }
+      for (const value of ["🚀x", "🚀🚀", "x", ""]) {
+        expect("issues" in schema["~standard"].validate(value)).toBe(true);
+      }
+      expect(schema.jsonSchema).toEqual({ type: "string", format: "emoji" });
+    });
+
+    it("should accept several emoji when allowed", () => {
+      const schema = h.emoji(true);
+      expect(schema["~standard"].validate("🚀👨‍👩‍👧👍🏽")).toEqual({ value: "🚀👨‍👩‍👧👍🏽" });
+      expect("issues" in schema["~standard"].validate("🚀 🚀")).toBe(true);
+    });
+  });
+
+  describe("h.slug()", () => {
+    it("should accept lowercase words joined by single hyphens", () => {
+      const schema = h.slug();
+      expect(schema["~standard"].validate("my-post-title")).toEqual({ value: "my-post-title" });
+      for (const value of ["My-Post", "-x", "x-", "a--b", "a b", ""]) {
+        expect("issues" in schema["~standard"].validate(value)).toBe(true);
+      }
+      expect(schema.jsonSchema).toEqual({ type: "string", format: "slug" });
+    });
+
+    it("should compose with length checks", () => {
+      const schema = h.slug().maxLength(5);
+      expect(schema["~standard"].validate("a-b")).toEqual({ value: "a-b" });
+      expect("issues" in schema["~standard"].validate("a-long-slug")).toBe(true);
+    });
+  });
+
+  describe("h.hex() / h.base32()", () => {
+    it("should accept even-length hex strings", () => {
+      expect(h.hex()["~standard"].validate("deadBEEF")).toEqual({ value: "deadBEEF" });
+      for (const value of ["xyz", "abc", "0xdeadbeef", ""]) {
+        expect("issues" in h.hex()["~standard"].validate(value)).toBe(true);
+      }
+      expect(h.hex().jsonSchema).toEqual({ type: "string", format: "hex" });
+    });
+
+    it("should accept a 0x prefix when allowed", () => {
+      expect(h.hex(true)["~standard"].validate("0xdeadbeef")).toEqual({ value: "0xdeadbeef" });
+      expect(h.hex(true)["~standard"].validate("deadbeef")).toEqual({ value: "deadbeef" });
+      expect("issues" in h.hex(true)["~standard"].validate("0x")).toBe(true);
+    });
+
+    it("should require uppercase base32 with correct padding", () => {
+      const schema = h.base32();
+      for (const value of ["MZXW6YTBOI======", "MZXW6===", "MZXW6YQ=", "GEZDGNBVGY3TQOJQ"]) {
+        expect(schema["~standard"].validate(value)).toEqual({ value });
+      }
+      for (const value of ["mzxw6===", "MZXW6", "MZXW6==", "MZX=====", "MZ1W6===", ""]) {
+        expect("issues" in schema["~standard"].validate(value)).toBe(true);
+      }
+      expect(schema.jsonSchema).toEqual({ type: "string", format: "base32" });
+    });
+  });
+
+  describe("h.dataUri()", () => {
+    it("should check the media type and payload", () => {
+      const png = "data:image/png;base64,iVBORw0KGgo=";
+      expect(h.dataUri()["~standard"].validate(png)).toEqual({ value: png });
+      expect(h.dataUri()["~standard"].validate("data:,Hello%20World")).toEqual({
+        value: "data:,Hello%20World",
+      });
+      expect(h.dataUri()["~standard"].validate("data:;x")).toEqual({
+        issues: [{ message: "Invalid data URI", code: "invalid_string" }],
+      });
+      const malformed = ["data:image/png;base64,iVBO*", "data:text/plain,%E0%A4%A"];
+      for (const uri of malformed) {
+        expect("issues" in h.dataUri()["~standard"].validate(uri)).toBe(true);
+      }
+    });
+
+    it("should restrict the MIME type prefixes when given", () => {
+      const image = h.dataUri(["image/"]);
+      expect("value" in image["~standard"].validate("data:image/gif;base64,R0lGOA==")).toBe(true);
+      expect("issues" in image["~standard"].validate("data:text/html,<p>hi</p>")).toBe(true);
+      expect(image.jsonSchema.format).toBe("data-uri");
+    });
+  });
+
+  describe("h.mimeType() / h.fileExtension()", () => {
+    it("should check type/subtype with optional parameters", () => {
+      for (const mime of ["image/png", "text/html; charset=utf-8", "application/vnd.api+json"]) {
+        expect(h.mimeType()["~standard"].validate(mime)).toEqual({ value: mime });
+      }
+      expect(h.mimeType()["~standard"].validate("image")).toEqual({
+        issues: [{ message: "Invalid MIME type", code: "invalid_string" }],
+      });
+      expect("issues" in h.mimeType()["~standard"].validate("text/html; charset")).toBe(true);
+      expect(h.mimeType().jsonSchema.format).toBe("mime-type");
+    });
+
+    it("should check the extension against the list, ignoring case", () => {
+      const document = h.fileExtension(["pdf", ".doc"]);
+      expect(document["~standard"].validate("report.PDF")).toEqual({ value: "report.PDF" });
+      expect(document["~standard"].validate("docs/v1.2/notes.doc")).toEqual({
+        value: "docs/v1.2/notes.doc",
+      });
+      expect(document["~standard"].validate("x.exe")).toEqual({
+        issues: [{ message: "File extension must be one of .pdf | .doc", code: "invalid_string" }],
+      });
+      expect("issues" in document["~standard"].validate("pdf")).toBe(true);
+      expect(document.jsonSchema["x-file-extensions"]).toEqual(["pdf", "doc"]);
+    });
+  });
+
+  describe("h.objectId()", () => {
+    it("should require exactly 24 hex characters", () => {
+      const id = "507f1F77bcf86cd799439011";
+      expect(h.objectId()["~standard"].validate(id)).toEqual({ value: id });
+      for (const value of [id.slice(1), `${id}0`, "507f1f77bcf86cd79943901z"]) {
+        expect(h.objectId()["~standard"].validate(value)).toEqual({
+          issues: [{ message: "Invalid ObjectId", code: "invalid_string" }],
+        });
+      }
+      expect(h.objectId().jsonSchema).toEqual({ type: "string", format: "objectid" });
+    });
+  });
+
+  describe("h.isbn()", () => {
+    it("should verify ISBN-10 and ISBN-13 check digits", () => {
+      expect(h.isbn()["~standard"].validate("0306406152")).toEqual({ value: "0306406152" });
+      expect(h.isbn()["~standard"].validate("0306406153")).toEqual({
+        issues: [{ message: "Invalid ISBN", code: "invalid_string" }],
+      });
+      expect(h.isbn()["~standard"].validate("978-0-306-40615-7")).toEqual({
+        value: "978-0-306-40615-7",
+      });
+      expect(h.isbn()["~standard"].validate("080442957X")).toEqual({ value: "080442957X" });
+      expect("issues" in h.isbn()["~standard"].validate("9780306406158")).toBe(true);
+    });
+
+    it("should restrict to one version when given", () => {
+      expect("issues" in h.isbn(13)["~standard"].validate("0306406152")).toBe(true);
+      expect("issues" in h.isbn(10)["~standard"].validate("9780306406157")).toBe(true);
+      expect(h.isbn(13).jsonSchema.format).toBe("isbn");
+    });
+  });
+
+  describe("h.nanoid()", () => {
+    it("should require 21 URL-safe characters by default", () => {
+      const id = "V1StGXR8_Z5jdHi6B-myT";
+      expect(h.nanoid()["~standard"].validate(id)).toEqual({ value: id });
+      for (const value of ["V1StGXR8_Z", `${id.slice(1)}!`]) {
+        expect(h.nanoid()["~standard"].validate(value)).toEqual({
+          issues: [{ message: "Invalid nanoid format", code: "invalid_string" }],
+        });
+      }
+      expect(h.nanoid().jsonSchema).toEqual({ type: "string", format: "nanoid" });
+    });
+
+    it("should accept a custom size and alphabet", () => {
+      const schema = h.nanoid(10, "0123456789abcdef");
+      expect(schema["~standard"].validate("4f90d13a42")).toEqual({ value: "4f90d13a42" });
+      expect("issues" in schema["~standard"].validate("4F90D13A42")).toBe(true);
+    });
+  });
+
+  describe("h.macAddress()", () => {
+    it("should accept colon and hyphen separated addresses by default", () => {
+      const schema = h.macAddress();
+      for (const mac of ["00:1A:2B:3C:4D:5E", "00-1a-2b-3c-4d-5e"]) {
+        expect(schema["~standard"].validate(mac)).toEqual({ value: mac });
+      }
+      const invalid = [
+        "00:1A:2B:3C:4D",
+        "00:1A-2B:3C:4D:5E",
+        "001A.2B3C.4D5E",
+        "00:1A:2B:3C:4D:5G",
+      ];
+      for (const mac of invalid) {
+        expect("issues" in schema["~standard"].validate(mac)).toBe(true);
+      }
+      expect(schema.jsonSchema).toEqual({ type: "string", format: "mac" });
+    });
+
+    it("should only accept the configured separators", () => {
+      const schema = h.macAddress(["."]);
+      expect(schema["~standard"].validate("001A.2B3C.4D5E")).toEqual({ value: "001A.2B3C.4D5E" });
+      expect("issues" in schema["~standard"].validate("00:1A:2B:3C:4D:5E")).toBe(true);
+    });
+  });
+
+  describe("h.hostname()", () => {
+    const schema = h.hostname();
+
+    it("should accept RFC 1123 hostnames", () => {
+      for (const host of ["my-host.example.com", "LOCALHOST", "example.com.", "1.example"]) {
+        expect(schema["~standard"].validate(host)).toEqual({ value: host });
+      }
+      expect(schema.jsonSchema).toEqual({ type: "string", format: "hostname" });
+    });
+
+    it("should reject malformed labels and overlong names", () => {
+      const tooLong = Array.from({ length: 5 }, () => "a".repeat(60)).join(".");
+      for (const host of ["-bad.com", "bad-.com", "under_score.com", "a..b", "", tooLong]) {
+        expect("issues" in schema["~standard"].validate(host)).toBe(true);
+      }
+      expect("issues" in schema["~standard"].validate(`${"a".repeat(64)}.com`)).toBe(true);
+    });
+  });
+
+  describe("ipOrHostname()", () => {
+    const schema = h.string().ipOrHostname();
+
+    it("should accept IPv4, IPv6 and hostnames", () => {
+      for (const host of ["127.0.0.1", "::1", "2001:db8::8a2e:370:7334", "::ffff:10.0.0.1"]) {
+        expect(schema["~standard"].validate(host)).toEqual({ value: host });
+      }
+      expect(schema["~standard"].validate("db.internal")).toEqual({ value: "db.internal" });
+    });
+
+    it("should report a single issue when nothing matches", () => {
+      expect(schema["~standard"].validate("not a host")).toEqual({
+        issues: [{ message: "Expected an IP address or hostname", code: "invalid_string" }],
+      });
+      for (const host of ["1::2::3", "1.2.3.4::", "12345::", "1:2:3:4:5:6:7:8:9"]) {
+        expect("issues" in schema["~standard"].validate(host)).toBe(true);
+      }
+    });
+  });
+
+  describe("h.currencyCode() / h.countryCode()", () => {
+    it("should check currencies against ISO 4217", () => {
+      const currency = h.currencyCode();
+      expect(currency["~standard"].validate("USD")).toEqual({ value: "USD" });
+      expect(currency["~standard"].validate("XYZ")).toEqual({
+        issues: [{ message: "Invalid currency code", code: "invalid_string" }],
+      });
+      expect(currency.jsonSchema.format).toBe("currency");
+      expect(currency.jsonSchema.enum).toContain("EUR");
+    });
+
+    it("should check countries against ISO 3166-1 alpha-2 or alpha-3", () => {
+      expect(h.countryCode()["~standard"].validate("US")).toEqual({ value: "US" });
+      expect("issues" in h.countryCode()["~standard"].validate("USA")).toBe(true);
+      expect(h.countryCode("alpha3")["~standard"].validate("USA")).toEqual({ value: "USA" });
+      expect("issues" in h.countryCode("alpha3")["~standard"].validate("XXX")).toBe(true);
+      expect(h.countryCode("alpha3").jsonSchema.enum).toHaveLength(249);
+    });
+  });
+
+  describe("h.postalCode()", () => {
+    it("should check the pattern of the given country", () => {
+      expect(h.postalCode("US")["~standard"].validate("90210")).toEqual({ value: "90210" });
+      expect(h.postalCode("US")["~standard"].validate("90210-1234")).toEqual({
+        value: "90210-1234",
+      });
+      expect(h.postalCode("CA")["~standard"].validate("90210")).toEqual({
+        issues: [{ message: "Invalid postal code", code: "invalid_string" }],
+      });
+      expect(h.postalCode("ca")["~standard"].validate("K1A 0B1")).toEqual({ value: "K1A 0B1" });
+      expect(h.postalCode("GB")["~standard"].validate("SW1A 1AA")).toEqual({ value: "SW1A 1AA" });
+    });
+
+    it("should fall back to a loose check for unknown countries", () => {
+      expect(h.postalCode("XX")["~standard"].validate("AB-123")).toEqual({ value: "AB-123" });
+      expect("issues" in h.postalCode("XX")["~standard"].validate("!")).toBe(true);
+    });
+
+    it("should emit the format and country", () => {
+      expect(h.postalCode("US").jsonSchema).toEqual({
+        type: "string",
+        format: "postal-code",
+        "x-country": "US",
+      });
+    });
+  });
+
+  describe("h.timezone()", () => {
+    it("should accept IANA zone names", () => {
+      const schema = h.timezone();
+      for (const zone of ["America/New_York", "Europe/Madrid", "UTC"]) {
+        expect(schema["~standard"].validate(zone)).toEqual({ value: zone });
+      }
+      expect(schema["~standard"].validate("Mars/Phobos")).toEqual({
+        issues: [{ message: "Invalid timezone", code: "invalid_string" }],
+      });
+      expect(schema.jsonSchema).toEqual({ type: "string", format: "timezone" });
+    });
+  });
+
+  describe("int() / safe()", () => {
+    it("should reject non-integers", () => {
+      const schema = h.number().int();
+      expect(schema["~standard"].validate(3)).toEqual({ value: 3 });
+      expect(schema["~standard"].validate(1.5)).toEqual({
+        issues: [
+          {
+            message: "Expected integer, received 1.5",
+            code: "invalid_type",
+            expected: "integer",
+            received: "number",
+          },
+        ],
+      });
+      expect(schema.jsonSchema).toEqual({ type: "integer" });
+    });
+
+    it("should bound safe integers", () => {
+      const schema = h.number().safe();
+      expect(schema["~standard"].validate(2 ** 53 - 1)).toEqual({ value: 2 ** 53 - 1 });
+      expect("issues" in schema["~standard"].validate(2 ** 53)).toBe(true);
+      expect("issues" in schema["~standard"].validate(0.5)).toBe(true);
+      expect(schema.jsonSchema).toEqual({
+        type: "integer",
+        minimum: Number.MIN_SAFE_INTEGER,
+        maximum: Number.MAX_SAFE_INTEGER,
+      });
+    });
+  });
+
+  describe("step()", () => {
+    it("should accept values aligned to the step", () => {
+      const schema = h.number().step(0, 5);
+      expect(schema["~standard"].validate(10)).toEqual({ value: 10 });
+      expect(schema["~standard"].validate(12)).toEqual({
+        issues: [{ message: "Number must be 0 plus a multiple of 5", code: "not_multiple_of" }],
+      });
+      expect(schema.jsonSchema).toEqual({ type: "number", multipleOf: 5 });
+    });
+
+    it("should handle fractional steps and a non-zero base", () => {
+      const quarter = h.number().step(0, 0.25);
+      expect(quarter["~standard"].validate(0.75)).toEqual({ value: 0.75 });
+      expect("issues" in quarter["~standard"].validate(0.3)).toBe(true);
+      const offset = h.number().step(1, 2);
+      expect(offset["~standard"].validate(3)).toEqual({ value: 3 });
+      expect("issues" in offset["~standard"].validate(4)).toBe(true);
+      expect(offset.jsonSchema.multipleOf).toBeUndefined();
+    });
+
+    it("should throw for a step that can't be checked", () => {
+      for (const step of [0, -1, Number.NaN, Number.POSITIVE_INFINITY]) {
+        expect(() => h.number().step(0, step)).toThrow(
+          "step() requires a finite step greater than 0",
+        );
+      }
+      expect(() => h.number().step(Number.NaN, 1)).toThrow("step() requires a finite base");
+    });
+  });
+
+  describe("port()", () => {
+    it("should accept integers between 0 and 65535", () => {
+      const schema = h.number().port();
+      expect(schema["~standard"].validate(8080)).toEqual({ value: 8080 });
+      expect("issues" in schema["~standard"].validate(70000)).toBe(true);
+      expect("issues" in schema["~standard"].validate(80.5)).toBe(true);
+      expect(schema.jsonSchema).toEqual({
+        type: "integer",
+        minimum: 0,
+        maximum: 65535,
+        format: "port",
+      });
+    });
+
+    it("should reject privileged ports when unprivileged is set", () => {
+      const schema = h.number().port(true);
+      expect("issues" in schema["~standard"].validate(80)).toBe(true);
+      expect(schema["~standard"].validate(1024)).toEqual({ value: 1024 });
+    });
+
+    it("should compose with coerce()", () => {
+      expect(h.number().coerce().port()["~standard"].validate("3000")).toEqual({ value: 3000 });
+    });
+  });
+
+  describe("int8() / uint8() / int16() / uint16() / int32() / uint32()", () => {
+    it("should accept integers within the width", () => {
+      expect(h.number().uint8()["~standard"].validate(255)).toEqual({ value: 255 });
+      expect(h.number().int8()["~standard"].validate(-128)).toEqual({ value: -128 });
+      expect(h.number().uint32()["~standard"].validate(4294967295)).toEqual({
+        value: 4294967295,
+      });
+    });
+
+    it("should reject values outside the width", () => {
+      expect(h.number().uint8()["~standard"].validate(256)).toEqual({
+        issues: [{ message: "Number must fit in uint8 (0..=255)", code: "too_big" }],
+      });
+      expect(h.number().int8()["~standard"].validate(-129)).toEqual({
+        issues: [{ message: "Number must fit in int8 (-128..=127)", code: "too_small" }],
+      });
+      expect(h.number().int16()["~standard"].validate(1.5)).toEqual({
+        issues: [{ message: "Number must fit in int16 (-32768..=32767)", code: "invalid_type" }],
+      });
+    });
+
+    it("should emit the bounds and width in the JSON Schema", () => {
+      expect(h.number().uint16().jsonSchema).toEqual({
+        type: "integer",
+        minimum: 0,
+        maximum: 65535,
+        "x-int-width": "uint16",
+      });
+    });
+
+    it("should use a custom message", () => {
+      expect(h.number().int32("Out of range")["~standard"].validate(2 ** 31)).toEqual({
+        issues: [{ message: "Out of range", code: "too_big" }],
+      });
+    });
+  });
+
+  describe("latitude() / longitude()", () => {
+    it("should bound coordinates", () => {
+      const latitude = h.number().latitude();
+      const longitude = h.number().longitude();
+      expect("issues" in latitude["~standard"].validate(91)).toBe(true);
+      expect(latitude["~standard"].validate(-90)).toEqual({ value: -90 });
+      expect(longitude["~standard"].validate(-180)).toEqual({ value: -180 });
+      expect("issues" in longitude["~standard"].validate(180.5)).toBe(true);
+    });
+
+    it("should compose with coerce()", () => {
+      expect(h.number().coerce().latitude()["~standard"].validate("45.5")).toEqual({ value: 45.5 });
+    });
+
+    it("should tag the JSON Schema format", () => {
+      expect(h.number().latitude().jsonSchema).toEqual({
+        type: "number",
+        minimum: -90,
+        maximum: 90,
+        format: "latitude",
+      });
+    });
+  });
+
+  describe("regex()", () => {
+    it("should emit the pattern instead of a format", () => {
+      const schema = h.string().regex(/^a.*z$/);
+      expect(schema.jsonSchema).toEqual({ type: "string", pattern: "^a.*z$" });
+      expect(schema.jsonSchema.format).toBeUndefined();
+      expect(h.string().uuid().jsonSchema.format).toBe("uuid");
+    });
+  });
+
+  describe("format()", () => {
+    registerFormat("sku", /^[A-Z]{3}-\d{4}$/);
+
+    it("should validate against a registered format", () => {
+      const schema = h.string().format("sku");
+      expect(schema["~standard"].validate("ABC-1234")).toEqual({ value: "ABC-1234" });
+      expect(schema["~standard"].validate("abc-1234")).toEqual({
+        issues: [{ message: "Invalid sku", code: "invalid_string" }],
+      });
+      expect(schema.jsonSchema).toEqual({ type: "string", format: "sku" });
+    });
+
+    it("should throw for an unknown format", () => {
+      expect(() => h.string().format("unknown")).toThrow("Unknown string format: unknown");
+    });
+  });
+
+  describe("validateWithOptions()", () => {
+    const schema = h.object({ name: h.string(), age: h.number(), tags: h.array(h.string()) });
+    const input = { name: 1, age: "x", tags: [1, 2] };
+
+    it("should collect every issue by default", () => {
+      const result = schema.validateWithOptions(input);
+      expect("issues" in result && result.issues.map((i) => i.path)).toEqual([
+        ["name"],
+        ["age"],
+        ["tags", 0],
+        ["tags", 1],
+      ]);
+      expect(schema["~standard"].validate(input)).toEqual(result);
+    });
+
+    it("should stop at the first issue when aborting early", () => {
+      expect(schema.validateWithOptions(input, { abortEarly: true })).toEqual({
+        issues: [
+          {
+            message: "Expected string, received number",
+            path: ["name"],
+            code: "invalid_type",
+            expected: "string",
+            received: "number",
+          },
+        ],
+      });
+      const tags = h.array(h.string()).validateWithOptions([1, 2], { abortEarly: true });
+      expect("issues" in tags && tags.issues).toHaveLength(1);
+    });
+
+    it("should stop collecting issues at maxIssues", () => {
+      const numbers = h.array(h.number());
+      const values = Array.from({ length: 5000 }, () => "x");
+      expect(numbers.validateWithOptions(values).issues).toHaveLength(1000);
+      expect(numbers.validateWithOptions(values, { maxIssues: 3 }).issues).toHaveLength(3);
+      const nested = h.object({ a: numbers, b: numbers });
+      const twice = nested.validateWithOptions({ a: values, b: values }, { maxIssues: 10 });
+      expect(twice.issues).toHaveLength(10);
+    });
+
+    it("should report deeply nested input instead of overflowing the stack", () => {
+      type Tree = Tree[];
+      const tree: BaseSchema<unknown, Tree> = h.array(h.lazy(() => tree));
+      let input: unknown[] = [];
+      for (let i = 0; i < 10000; i++) {
+        input = [input];
+      }
+      const result = tree.validateWithOptions(input);
+      expect(result.issues?.[0]?.message).toBe("Maximum recursion depth exceeded");
+      const shallow = tree.validateWithOptions(input, { maxDepth: 2 });
+      expect(shallow.issues?.[0]?.path).toEqual([0, 0, 0]);
+    });
+  });
+
+  describe("describe()", () => {
+    it("should persist the description before and after other builders", () => {
+      const before = h.string().describe("The user's email").email();
+      const after = h.string().email().describe("The user's email");
+      for (const schema of [before, after]) {
+        expect(schema.jsonSchema).toEqual({
+          type: "string",
+          format: "email",
+          description: "The user's email",
+        });
+      }
+      expect(after.description).toBe("The user's email");
+    });
+
+    it("should not change validation", () => {
+      const schema = h.number().describe("Age").min(0);
+      expect("issues" in schema["~standard"].validate(-1)).toBe(true);
+    });
+  });
+
+  describe("introspection", () => {
+    const form = h.object({
+      name: h.string().minLength(2).maxLength(50),
+      age: h.number().optional(),
+      birthday: h.dateType().nullable(),
+      tags: h.array(h.string()),
+      address: { city: h.string() },
+    });
+
+    it("should expose the keys and the type of each field", () => {
+      const fields = form.getKeys().map((key) => {
+        const field = form.shape(key);
+        return [key, field.getType(), field.isOptional()];
+      });
+      expect(fields).toEqual([
+        ["name", "string", false],
+        ["age", "number", true],
+        ["birthday", "date", false],
+        ["tags", "array", false],
+        ["address", "object", false],
+      ]);
+    });
+
+    it("should expose string length constraints", () => {
+      const name = h.string().minLength(2).maxLength(50);
+      expect(name.getMinLength()).toBe(2);
+      expect(name.getMaxLength()).toBe(50);
+      expect(h.string().getMinLength()).toBeUndefined();
+    });
+
+    it("should report unknown for composite schemas", () => {
+      expect(h.options(h.string(), h.number()).getType()).toBe("unknown");
+      expect(h.bigint().getType()).toBe("bigint");
+      expect(h.string().default("a").isOptional()).toBe(true);
+    });
+  });
+
+  describe("meta()", () => {
+    it("should merge annotations into the JSON Schema", () => {
+      const schema = h.string().email().meta({ title: "Name", example: "Ada", deprecated: true });
+      expect(schema.jsonSchema).toEqual({
+        type: "string",
+        format: "email",
+        title: "Name",
+        example: "Ada",
+        deprecated: true,
+      });
+    });
+
+    it("should let later calls win without clobbering type or format", () => {
+      const schema = h
+        .number()
+        .meta({ title: "Old", "x-order": 1 })
+        .meta({ title: "Age", type: "string", format: "x" });
+      expect(schema.jsonSchema).toEqual({ type: "number", title: "Age", "x-order": 1 });
+    });
+  });
+
+  describe("string().coerce()", () => {
+    it("should trim and lowercase emails before checking them", () => {
+      const email = h.string().email().coerce();
+      expect(email["~standard"].validate(" User@Example.COM ")).toEqual({
+        value: "user@example.com",
+      });
+      expect("issues" in h.string().email()["~standard"].validate(" User@Example.COM ")).toBe(true);
+    });
+
+    it("should trim and uppercase ISO codes", () => {
+      expect(h.countryCode().coerce()["~standard"].validate(" us")).toEqual({ value: "US" });
+      expect(h.currencyCode().coerce()["~standard"].validate("eur")).toEqual({ value: "EUR" });
+      expect("issues" in h.countryCode()["~standard"].validate("us")).toBe(true);
+    });
+  });
+
+  describe("boolean().coerce()", () => {
+    const schema = h.boolean().coerce();
+
+    it("should map the truth table", () => {
+      for (const input of ["true", "TRUE", "1", "yes", "On", 1, true]) {
+        expect(schema["~standard"].validate(input)).toEqual({ value: true });
+      }
+      for (const input of ["false", "False", "0", "no", "OFF", "", 0, false]) {
+        expect(schema["~standard"].validate(input)).toEqual({ value: false });
+      }
+    });
+
+    it("should reject anything else", () => {
+      for (const input of ["maybe", 2, null, {}]) {
+        expect("issues" in schema["~standard"].validate(input)).toBe(true);
+      }
+    });
+  });
+
+  describe("number().coerce()", () => {
+    const schema = h.number().coerce();
+
+    it("should trim strings before parsing", () => {
+      expect(schema["~standard"].validate(" 42 ")).toEqual({ value: 42 });
+      expect(schema["~standard"].validate("-1.5")).toEqual({ value: -1.5 });
+    });
+
+    it("should report empty strings distinctly", () => {
+      for (const input of ["", "   "]) {
+        expect(schema["~standard"].validate(input)).toEqual({
+          issues: [
+            {
+              message: "Expected number, received empty string",
+              code: "invalid_type",
+              expected: "number",
+              received: "empty string",
+            },
+          ],
+        });
+      }
+    });
+
+    it("should reject non-numeric and non-finite strings", () => {
+      for (const input of ["abc", "NaN", "Infinity", "-Infinity"]) {
+        expect("issues" in schema["~standard"].validate(input)).toBe(true);
+      }
+    });
+  });
+
+  describe("h.dateType()", () => {
+    it("should accept Date instances", () => {
+      const date = new Date("2024-01-01T00:00:00Z");
+      const result = h.dateType()["~standard"].validate(date);
+      expect(result).toEqual({ value: date });
+    });
+
+    it("should reject Invalid Date", () => {
+      const result = h.dateType()["~standard"].validate(new Date("invalid"));
+      expect("issues" in result && result.issues[0]?.message).toBe("Invalid Date");
+    });
+
+    it("should reject non-Date values", () => {
+      const result = h.dateType()["~standard"].validate("2024-01-01");
+      expect("issues" in result).toBe(true);
+    });
+
+    it("should enforce minDate and maxDate", () => {
+      const schema = h
+        .dateType()
+        .minDate(Date.UTC(2024, 0, 1))
+        .maxDate(new Date("2024-12-31T00:00:00Z"));
+      expect("issues" in schema["~standard"].validate(new Date("2023-06-01"))).toBe(true);
+      expect("issues" in schema["~standard"].validate(new Date("2025-06-01"))).toBe(true);
+      expect("value" in schema["~standard"].validate(new Date("2024-06-01"))).toBe(true);
+    });
+
+    it("should reject future dates with maxDate(now) and alias before() / after()", () => {
+      const birthdate = h.dateType().maxDate(new Date());
+      expect("issues" in birthdate["~standard"].validate(new Date(Date.now() + 60_000))).toBe(true);
+      expect("value" in birthdate["~standard"].validate(new Date("1990-05-01"))).toBe(true);
+      const range = h
+        .dateType()
+        .coerce()
+        .after("2024-01-01T00:00:00Z")
+        .before("2024-02-01T00:00:00Z");
+      expect(range["~standard"].validate("2024-01-15")).toEqual({ value: new Date("2024-01-15") });
+      expect(range["~standard"].validate("2024-03-01")).toEqual({
+        issues: [{ message: "Date later than 2024-02-01T00:00:00.000Z", code: "too_big" }],
+      });
+      expect(() => h.dateType().minDate("soon")).toThrow();
+    });
+
+    it("should emit the bounds as ISO strings", () => {
+      expect(h.dateType().minDate(0).maxDate(Date.UTC(2024, 0, 1)).jsonSchema).toEqual({
+        type: "string",
+        format: "date-time",
+        "x-minDate": "1970-01-01T00:00:00.000Z",
+        "x-maxDate": "2024-01-01T00:00:00.000Z",
+      });
+    });
+
+    it("should coerce strings and numbers when coerce() is used", () => {
+      const schema = h.dateType().coerce();
+      expect(schema["~standard"].validate("2023-01-01")).toEqual({
+        value: new Date("2023-01-01"),
+      });
+      expect(schema["~standard"].validate(0)).toEqual({ value: new Date(0) });
+      const invalid = schema["~standard"].validate("not a date");
+      expect("issues" in invalid && invalid.issues[0]?.message).toBe("Invalid Date");
+      expect("issues" in h.dateType()["~standard"].validate("2023-01-01")).toBe(true);
+    });
+
+    it("should emit a date-time JSON Schema", () => {
+      expect(h.dateType().jsonSchema).toEqual({ type: "string", format: "date-time" });
+    });
+  });
+
+  describe("h.bigint()", () => {
+    it("should accept bigint values", () => {
+      expect(h.bigint()["~standard"].validate(9007199254740993n)).toEqual({
+        value: 9007199254740993n,
+      });
+    });
+
+    it("should reject numbers", () => {
+      expect("issues" in h.bigint()["~standard"].validate(1)).toBe(true);
+    });
+
+    it("should enforce min and max", () => {
+      const schema = h.bigint().min(0n).max(10n);
+      expect("issues" in schema["~standard"].validate(-1n)).toBe(true);
+      expect("issues" in schema["~standard"].validate(11n)).toBe(true);
+      expect(schema["~standard"].validate(5n)).toEqual({ value: 5n });
+    });
+
+    it("should coerce integer strings and numbers without losing precision", () => {
+      const schema = h.bigint().coerce().min(0n);
+      expect(schema["~standard"].validate("12345678901234567890")).toEqual({
+        value: 12345678901234567890n,
+      });
+      expect(schema["~standard"].validate(42)).toEqual({ value: 42n });
+      expect("issues" in schema["~standard"].validate("-1")).toBe(true);
+      expect("issues" in schema["~standard"].validate("1.5")).toBe(true);
+      expect("issues" in schema["~standard"].validate(1.5)).toBe(true);
+    });
+
+    it("should accept number bounds", () => {
+      const schema = h.bigint().min(0.5).max(10);
+      expect("issues" in schema["~standard"].validate(0n)).toBe(true);
+      expect(schema["~standard"].validate(1n)).toEqual({ value: 1n });
+      expect(schema["~standard"].validate(10n)).toEqual({ value: 10n });
+    });
+
+    it("should reject non-finite number bounds", () => {
+      expect(() => h.bigint().min(Number.NaN)).toThrow(
+        "min() requires a finite number or a bigint",
+      );
+      expect(() => h.bigint().max(Number.POSITIVE_INFINITY)).toThrow(
+        "max() requires a finite number or a bigint",
+      );
+    });
+
+    it("should emit minimum and maximum when a JSON number holds them exactly", () => {
+      expect(h.bigint().min(0.5).max(10n).jsonSchema).toEqual({
+        type: "integer",
+        format: "bigint",
+        minimum: 1,
+        maximum: 10,
+      });
+      expect(h.bigint().min(2n ** 64n).jsonSchema.minimum).toBeUndefined();
+    });
+  });
+
+  describe("h.never() / h.void()", () => {
+    it("should reject every value with never()", () => {
+      const inputs: [unknown, string][] = [
+        [undefined, "undefined"],
+        [null, "null"],
+        [0, "number"],
+        ["", "string"],
+        [{}, "object"],
+      ];
+      for (const [input, received] of inputs) {
+        expect(h.never()["~standard"].validate(input)).toEqual({
+          issues: [
+            { message: "No value is valid", code: "invalid_type", expected: "never", received },
+          ],
+        });
+      }
+      expect(h.never().jsonSchema).toEqual({ not: {} });
+    });
+
+    it("should accept only undefined with void()", () => {
+      expect(h.void()["~standard"].validate(undefined)).toEqual({ value: undefined });
+      expect(h.void()["~standard"].validate(0)).toEqual({
+        issues: [
+          {
+            message: "Expected undefined, received number",
+            code: "invalid_type",
+            expected: "undefined",
+            received: "number",
+          },
+        ],
+      });
+    });
+  });
+
+  describe("h.function()", () => {
+    it("should accept functions only", () => {
+      const callback = (a: number) => a;
+      expect(h.function()["~standard"].validate(callback)).toEqual({ value: callback });
+      expect(h.function()["~standard"].validate(1)).toEqual({
+        issues: [
+          {
+            message: "Expected function, received number",
+            code: "invalid_type",
+            expected: "function",
+            received: "number",
+          },
+        ],
+      });
+    });
+
+    it("should check the declared parameter count", () => {
+      const add = (a: number, b: number) => a + b;
+      expect(h.function().minArgs(2)["~standard"].validate(add)).toEqual({ value: add });
+      expect(h.function(3)["~standard"].validate(add)).toEqual({
+        issues: [
+          {
+            message: "Function must accept at least 3 argument(s), received 2",
+            code: "too_small",
+          },
+        ],
+      });
+    });
+
+    it("should emit a function hint", () => {
+      expect(h.function().jsonSchema).toEqual({ type: "object", "x-type": "function" });
+      expect(h.function().getType()).toBe("function");
+    });
+  });
+
+  describe("h.promise()", () => {
+    const schema = h.promise(h.string());
+
+    it("should validate the resolved value with validateAsync()", async () => {
+      expect("value" in (await schema.validateAsync(Promise.resolve("ok")))).toBe(true);
+      expect(await schema.validateAsync(Promise.resolve(1))).toEqual({
+        issues: [
+          {
+            message: "Expected string, received number",
+            code: "invalid_type",
+            expected: "string",
+            received: "number",
+          },
+        ],
+      });
+    });
+
+    it("should report rejections and nest paths", async () => {
+      expect(await schema.validateAsync(Promise.reject(new Error("boom")))).toEqual({
+        issues: [{ message: "Promise rejected: boom", code: "custom" }],
+      });
+      const result = await h.object({ data: schema }).validateAsync({ data: Promise.resolve(1) });
+      expect(result.issues?.[0]?.path).toEqual(["data"]);
+    });
+
+    it("should resolve to the parsed inner output", async () => {
+      const trimmed = h.promise(h.string().trim());
+      const result = await trimmed.validateAsync(Promise.resolve("  ok "));
+      expect(await result.value).toBe("ok");
+      const defaulted = await h.promise(h.number().default(1)).validateAsync(Promise.resolve());
+      expect(await defaulted.value).toBe(1);
+    });
+
+    it("should forward maxIssues and abortEarly to the inner schema", async () => {
+      const numbers = h.promise(h.array(h.number()));
+      const input = () => Promise.resolve(["a", "b", "c"]);
+      expect((await numbers.validateAsync(input())).issues).toHaveLength(3);
+      expect((await numbers.validateAsync(input(), { maxIssues: 2 })).issues).toHaveLength(2);
+      expect((await numbers.validateAsync(input(), { abortEarly: true })).issues).toHaveLength(1);
+    });
+
+    it("should only check for a promise synchronously", async () => {
+      const result = schema.validateWithOptions(Promise.resolve(1));
+      expect(result.value).toBeInstanceOf(Promise);
+      await expect(result.value).rejects.toThrow("Expected string, received number");
+      expect(schema.validateWithOptions("ok")).toEqual({
+        issues: [
+          {
+            message: "Expected Promise, received string",
+            code: "invalid_type",
+            expected: "Promise",
+            received: "string",
+          },
+        ],
+      });
+    });
+  });
+
+  describe("h.lazy()", () => {
+    type Node = { value: number; children: Node[] };
+    const node: ObjectSchemaType<Node> = h.object({
+      value: h.number(),
+      children: h.array(h.lazy(() => node)),
+    });
+
+    it("should validate a recursive tree", () => {
+      const tree = {
+        value: 1,
+        children: [{ value: 2, children: [{ value: 3, children: [] }] }],
+      };
+      expect(node["~standard"].validate(tree)).toEqual({ value: tree });
+    });
+
+    it("should report nested issues with their path", () => {
+      const tree = { value: 1, children: [{ value: 2, children: [{ value: "3", children: [] }] }] };
+      expect(node["~standard"].validate(tree)).toEqual({
+        issues: [
+          {
+            message: "Expected number, received string",
+            path: ["children", 0, "children", 0, "value"],
+            code: "invalid_type",
+            expected: "number",
+            received: "string",
+          },
+        ],
+      });
+    });
+
+    it("should stop on cyclic data instead of overflowing the stack", () => {
+      const cyclic: any = { value: 1, children: [] };
+      cyclic.children.push(cyclic);
+      const result = node["~standard"].validate(cyclic);
+      expect("issues" in result && result.issues[0]?.message).toBe(
+        "Maximum recursion depth exceeded",
+      );
+    });
+  });
+});
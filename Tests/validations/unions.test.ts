@@ -0,0 +1,235 @@
+import { describe, expect, it } from "bun:test";
+import { h, setErrorMap } from "@hedystia/validations";
+
+describe("Unions", () => {
+  describe("h.discriminatedUnion()", () => {
+    const schema = h.discriminatedUnion("type", [
+      h.object({ type: h.literal("a"), a: h.string() }),
+      h.object({ type: h.literal("b"), b: h.number() }),
+    ]);
+
+    it("should validate the option selected by the discriminator", () => {
+      expect(schema["~standard"].validate({ type: "a", a: "x" })).toEqual({
+        value: { type: "a", a: "x" },
+      });
+      expect(schema["~standard"].validate({ type: "b", b: 1 })).toEqual({
+        value: { type: "b", b: 1 },
+      });
+    });
+
+    it("should only report issues from the selected option", () => {
+      expect(schema["~standard"].validate({ type: "b", b: "x" })).toEqual({
+        issues: [
+          {
+            message: "Expected number, received string",
+            path: ["b"],
+            code: "invalid_type",
+            expected: "number",
+            received: "string",
+          },
+        ],
+      });
+    });
+
+    it("should report an unknown discriminator value", () => {
+      expect(schema["~standard"].validate({ type: "c" })).toEqual({
+        issues: [
+          {
+            message: 'Invalid discriminator value. Expected "a" | "b"',
+            path: ["type"],
+            code: "invalid_union_discriminator",
+          },
+        ],
+      });
+    });
+
+    it("should reject options without a literal discriminator", () => {
+      expect(() => h.discriminatedUnion("type", [h.object({ type: h.string() })])).toThrow();
+    });
+
+    it("should emit oneOf with a discriminator", () => {
+      expect(schema.jsonSchema.discriminator).toEqual({ propertyName: "type" });
+      expect(schema.jsonSchema.oneOf).toHaveLength(2);
+    });
+  });
+
+  describe("h.options()", () => {
+    it("should report only the issues of the closest object option", () => {
+      const schema = h.options(
+        h.object({ kind: h.literal("user"), name: h.string(), email: h.string() }),
+        h.object({ id: h.number() }),
+      );
+      expect(schema["~standard"].validate({ id: "1" })).toEqual({
+        issues: [
+          {
+            message: "Expected number, received string",
+            path: ["id"],
+            code: "invalid_type",
+            expected: "number",
+            received: "string",
+          },
+        ],
+      });
+    });
+
+    it("should report every root mismatch when no option matches the type", () => {
+      const schema = h.options(h.string(), h.number());
+      expect(schema["~standard"].validate(true)).toEqual({
+        issues: [
+          {
+            message: "Expected string, received boolean",
+            code: "invalid_type",
+            expected: "string",
+            received: "boolean",
+          },
+          {
+            message: "Expected number, received boolean",
+            code: "invalid_type",
+            expected: "number",
+            received: "boolean",
+          },
+        ],
+      });
+    });
+  });
+
+  describe("JSON Schema", () => {
+    it("should collapse a union of literals into an enum", () => {
+      expect(h.options(h.literal("a"), h.literal("b")).jsonSchema).toEqual({
+        type: "string",
+        enum: ["a", "b"],
+      });
+      expect(h.options(h.literal("a"), h.literal(1)).jsonSchema).toEqual({ enum: ["a", 1] });
+    });
+
+    it("should keep anyOf for other unions", () => {
+      expect(h.options(h.literal("a"), h.number()).jsonSchema).toEqual({
+        anyOf: [{ const: "a", type: "string" }, { type: "number" }],
+      });
+      const described = h.options(h.literal("a"), h.literal("b").describe("Second"));
+      expect(described.jsonSchema.anyOf).toHaveLength(2);
+    });
+  });
+
+  describe("type probing", () => {
+    it("should skip options whose type cannot match", () => {
+      let calls = 0;
+      const variants = Array.from({ length: 20 }, (_, i) => {
+        const variant = h.object({ kind: h.literal(i), data: h.array(h.number()) });
+        const standard = variant["~standard"];
+        Object.defineProperty(variant, "~standard", {
+          get: () => ({
+            ...standard,
+            validate: (value: unknown) => {
+              calls++;
+              return standard.validate(value);
+            },
+          }),
+        });
+        return variant;
+      });
+      const schema = h.options(...variants, h.string());
+      expect(schema["~standard"].validate("ok")).toEqual({ value: "ok" });
+      expect(calls).toBe(0);
+    });
+
+    it("should still report every root mismatch", () => {
+      const schema = h.options(h.object({ a: h.string() }), h.array(h.string()));
+      expect(schema.validateWithOptions(1).issues?.map((issue) => issue.message)).toEqual([
+        "Expected object, received number",
+        "Expected array, received number",
+      ]);
+    });
+
+    it("should always run options that coerce", () => {
+      const schema = h.options(h.boolean(), h.number().coerce());
+      expect(schema["~standard"].validate("5")).toEqual({ value: 5 });
+    });
+  });
+
+  describe("h.literals()", () => {
+    const schema = h.literals([1, 2, 3]);
+
+    it("should accept any of the values", () => {
+      expect(schema["~standard"].validate(2)).toEqual({ value: 2 });
+    });
+
+    it("should report every allowed value in one issue", () => {
+      expect(schema["~standard"].validate(4)).toEqual({
+        issues: [{ message: "Expected one of 1 | 2 | 3, received 4", code: "invalid_literal" }],
+      });
+      expect(h.literals(["a", "b"])["~standard"].validate("c")).toEqual({
+        issues: [{ message: 'Expected one of "a" | "b", received "c"', code: "invalid_literal" }],
+      });
+    });
+
+    it("should describe values JSON cannot serialise", () => {
+      const cyclic: Record<string, unknown> = {};
+      cyclic.self = cyclic;
+      const messages = [1n, undefined, () => 1, Symbol("s"), cyclic].map(
+        (value) => schema.validateWithOptions(value).issues?.[0]?.message,
+      );
+      expect(messages).toEqual([
+        "Expected one of 1 | 2 | 3, received bigint",
+        "Expected one of 1 | 2 | 3, received undefined",
+        "Expected one of 1 | 2 | 3, received function",
+        "Expected one of 1 | 2 | 3, received symbol",
+        "Expected one of 1 | 2 | 3, received object",
+      ]);
+    });
+
+    it("should pass expected and received to the error map", () => {
+      const seen: unknown[] = [];
+      setErrorMap((issue) => {
+        seen.push([issue.code, issue.expected, issue.received]);
+        return "Pick a listed value";
+      });
+      const result = schema.validateWithOptions("4");
+      setErrorMap(undefined);
+      expect(seen).toEqual([["invalid_literal", "1 | 2 | 3", "string"]]);
+      expect(result.issues?.[0]?.message).toBe("Pick a listed value");
+    });
+
+    it("should emit an enum", () => {
+      expect(schema.jsonSchema).toEqual({ type: "number", enum: [1, 2, 3] });
+      expect(h.literals(["a", 1]).jsonSchema).toEqual({ enum: ["a", 1] });
+    });
+  });
+
+  describe("h.intersection()", () => {
+    const schema = h.intersection(h.object({ a: h.string() }), h.object({ b: h.number() }));
+
+    it("should require both sides and merge the outputs", () => {
+      expect(schema["~standard"].validate({ a: "x", b: 1 })).toEqual({ value: { a: "x", b: 1 } });
+    });
+
+    it("should collect issues from both sides", () => {
+      expect(schema["~standard"].validate({})).toEqual({
+        issues: [
+          {
+            message: "Missing required property: a",
+            path: ["a"],
+            code: "invalid_type",
+            expected: "string",
+            received: "undefined",
+          },
+          {
+            message: "Missing required property: b",
+            path: ["b"],
+            code: "invalid_type",
+            expected: "number",
+            received: "undefined",
+          },
+        ],
+      });
+    });
+
+    it("should be available as and() and emit allOf", () => {
+      const combined = h.object({ a: h.string() }).and(h.object({ b: h.number() }));
+      expect(combined["~standard"].validate({ a: "x", b: 1 })).toEqual({
+        value: { a: "x", b: 1 },
+      });
+      expect(combined.jsonSchema.allOf).toHaveLength(2);
+    });
+  });
+});